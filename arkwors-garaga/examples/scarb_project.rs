@@ -0,0 +1,40 @@
+use std::{fs::File, path::PathBuf};
+
+use arkwors_garaga::{scarb::ScarbProject, AnyGroth16VerifierConfiguration, GaragaError};
+
+/// Write a Scarb package holding the Garaga verifier contract of a verifier config `.json`
+///
+/// Keys that don't pass `Groth16VerifierConfiguration::audit` are refused.
+///
+/// * Args
+/// 1) `config`: the path to the verifier config `.json`, as written by the other examples
+/// 2) `name`: the name of the Scarb package
+/// 3) `output_dir`: the path of the directory to write the package into
+/// 4) `ecip_ops_class_hash`: the class hash of the Garaga ECIP ops contract declared on the target network
+///
+/// * Usage
+/// ```shell
+/// cargo run --example scarb_project /tmp/silly_circuit_verifier/verifier_config.json silly_verifier /tmp/silly_verifier 0x684d2756a4440c190a5fe54e367c0abe33aefa75084dec2fffc791b620c80e3
+/// cd /tmp/silly_verifier && scarb build
+/// ```
+fn main() -> Result<(), GaragaError> {
+    let mut args = std::env::args().skip(1);
+    let mut next_arg = || args.next().expect("four arguments should be passed");
+    let config_path = PathBuf::from(next_arg());
+    let name = next_arg();
+    let output_dir = PathBuf::from(next_arg());
+    let ecip_ops_class_hash = next_arg();
+
+    let project = ScarbProject::new(&name, &ecip_ops_class_hash)?;
+    let config: AnyGroth16VerifierConfiguration =
+        serde_json::from_reader(File::open(config_path)?).map_err(std::io::Error::from)?;
+
+    match &config {
+        AnyGroth16VerifierConfiguration::Bn254(config) => {
+            project.write(config.audited()?, &output_dir)
+        }
+        AnyGroth16VerifierConfiguration::Bls12_381(config) => {
+            project.write(config.audited()?, &output_dir)
+        }
+    }
+}
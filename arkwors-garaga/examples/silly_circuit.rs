@@ -6,9 +6,8 @@ use std::{
 };
 
 use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
-use ark_ec::pairing::Pairing;
 use ark_ff::Field;
-use ark_groth16::{prepare_verifying_key, Groth16};
+use ark_groth16::Groth16;
 use ark_relations::{
     lc,
     r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
@@ -17,7 +16,10 @@ use ark_std::{
     rand::{RngCore, SeedableRng},
     test_rng, UniformRand,
 };
-use arkwors_garaga::{ElipticCurveId, Groth16VerifierConfiguration, Groth16VerifierInputs};
+use arkwors_garaga::{
+    GaragaCurve, GaragaError, Groth16ProofBundle, Groth16VerifierConfiguration,
+    Groth16VerifierInputs,
+};
 
 struct MySillyCircuit<F: Field> {
     a: Option<F>,
@@ -50,23 +52,20 @@ impl<ConstraintF: Field> ConstraintSynthesizer<ConstraintF> for MySillyCircuit<C
     }
 }
 
-fn generate_garaga_jsons<E>(n_iters: usize, dump_dir: &Path)
+fn generate_garaga_jsons<E>(n_iters: usize, dump_dir: &Path) -> Result<(), GaragaError>
 where
-    E: Pairing,
+    E: GaragaCurve,
 {
     let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(test_rng().next_u64());
 
     let (pk, vk) = Groth16::<E>::setup(MySillyCircuit { a: None, b: None }, &mut rng).unwrap();
-    let pvk = prepare_verifying_key::<E>(&vk);
 
     // Serialize and write verifier config
+    let config = Groth16VerifierConfiguration::from_verifying_key(vk);
     {
-        let config = Groth16VerifierConfiguration::new(ElipticCurveId::Bn254, vk.clone());
         let config_json_file =
-            std::fs::File::create(dump_dir.to_path_buf().join("verifier_config.json"))
-                .expect("failed to create and/or open the `verifier_config.json` file");
-        serde_json::to_writer(config_json_file, &config)
-            .expect("should be able to serialize the verifier config");
+            std::fs::File::create(dump_dir.to_path_buf().join("verifier_config.json"))?;
+        serde_json::to_writer(config_json_file, config.audited()?).map_err(std::io::Error::from)?;
     }
 
     for _ in 0..n_iters {
@@ -85,23 +84,33 @@ where
         )
         .unwrap();
 
-        assert!(Groth16::<E>::verify_with_processed_vk(&pvk, &[c], &proof).unwrap());
-        // Serialize and write verifier inputs
+        // Verify proof, then serialize and write verifier inputs
         {
-            let inputs = Groth16VerifierInputs::new(ElipticCurveId::Bn254, proof, vec![c]);
+            let inputs = Groth16VerifierInputs::from_proof(proof, vec![c]);
+            inputs.verify(&config)?;
             let input_json_file = std::fs::File::create(
                 dump_dir
                     .to_path_buf()
                     .join(format!("verifier_inputs_{}.json", c)),
-            )
-            .expect("failed to create and/or open the `verifier_input.json` file");
-            serde_json::to_writer(input_json_file, &inputs)
-                .expect("should be able to serialize the verifier config");
+            )?;
+            serde_json::to_writer(input_json_file, &inputs).map_err(std::io::Error::from)?;
+
+            // The same proof, bundled with the verifying key in a single document
+            let bundle = Groth16ProofBundle::new(config.clone(), inputs)?;
+            let bundle_json_file = std::fs::File::create(
+                dump_dir
+                    .to_path_buf()
+                    .join(format!("proof_bundle_{}.json", c)),
+            )?;
+            serde_json::to_writer(bundle_json_file, &bundle).map_err(std::io::Error::from)?;
         }
     }
+
+    Ok(())
 }
 
-/// Run the `SillyCircuit` `n_iters`, verify the generated proof and dump the garaga verifier `.json` files under `output_dir`
+/// Run the `SillyCircuit` `n_iters`, verify the generated proof and dump the garaga verifier `.json` files,
+/// and the proof bundles holding both, under `output_dir`
 ///
 /// * Args
 /// 1) `n_iters`: the number of times you want to run the circuit with a different input
@@ -112,7 +121,7 @@ where
 /// mkdir /tmp/silly_circuit_verifier/
 /// cargo run --example silly_circuit 3 /tmp/silly_circuit_verifier/
 /// ```
-fn main() {
+fn main() -> Result<(), GaragaError> {
     let (n_iters, dir_output) = {
         let mut args = std::env::args();
         let n_iters = args.nth(1).expect("two argument should be passed");
@@ -127,5 +136,5 @@ fn main() {
         (n_iters, dir_output)
     };
 
-    generate_garaga_jsons::<ark_bn254::Bn254>(n_iters.into(), &dir_output);
+    generate_garaga_jsons::<ark_bn254::Bn254>(n_iters.into(), &dir_output)
 }
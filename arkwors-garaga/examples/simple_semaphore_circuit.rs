@@ -3,14 +3,14 @@ use std::{path::PathBuf, str::FromStr};
 use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
 use ark_ec::pairing::Pairing;
 use ark_ff::Field;
-use ark_groth16::{prepare_verifying_key, Groth16};
+use ark_groth16::Groth16;
 use ark_r1cs_std::{alloc::AllocVar, boolean::Boolean, eq::EqGadget, uint8::UInt8};
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 use ark_std::{
     rand::{RngCore, SeedableRng},
     test_rng,
 };
-use arkwors_garaga::{ElipticCurveId, Groth16VerifierConfiguration, Groth16VerifierInputs};
+use arkwors_garaga::{GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs};
 
 struct IsPartOfPublicInputsCircuit<'a, const N_PUBLIC_INPUTS: usize> {
     secret_value: Option<u8>,
@@ -69,7 +69,8 @@ impl<'a, const N_PUBLIC_INPUTS: usize, ConstraintF: Field> ConstraintSynthesizer
 ///
 /// In order to be able to generate a proof, the secret should be part of the public inputs too.
 /// The number of public input is constrained by the `N_PUB_INPUTS` constant.
-fn main() {
+#[allow(clippy::doc_lazy_continuation)]
+fn main() -> Result<(), GaragaError> {
     // Edit this const to change the number of public inputs
     const N_PUB_INPUTS: usize = 3;
 
@@ -108,17 +109,14 @@ fn main() {
         &mut rng,
     )
     .unwrap();
-    let pvk = prepare_verifying_key::<ark_bn254::Bn254>(&vk);
     // Serialize and write verifier config
+    let config = Groth16VerifierConfiguration::from_verifying_key(vk);
     {
-        let config = Groth16VerifierConfiguration::new(ElipticCurveId::Bn254, vk.clone());
         let config_json_file = std::fs::File::create(output_dir.to_path_buf().join(format!(
             "verifier_config_for_secret_{}_and_{}_pub_inputs.json",
             secret_value, N_PUB_INPUTS
-        )))
-        .expect("failed to create and/or open the `verifier_config.json` file");
-        serde_json::to_writer(config_json_file, &config)
-            .expect("should be able to serialize the verifier config");
+        )))?;
+        serde_json::to_writer(config_json_file, config.audited()?).map_err(std::io::Error::from)?;
     }
 
     // Generate proof
@@ -144,12 +142,6 @@ fn main() {
         }
     }
 
-    // Verify proof
-    assert!(
-        Groth16::<ark_bn254::Bn254>::verify_with_processed_vk(&pvk, &verifier_inputs, &proof)
-            .unwrap()
-    );
-
     // Serialize and write verifier inputs
     {
         let mut list_of_pub_inputs_as_string = String::new();
@@ -157,13 +149,15 @@ fn main() {
             list_of_pub_inputs_as_string.push_str(&format!("_{v}"));
         }
 
-        let inputs = Groth16VerifierInputs::new(ElipticCurveId::Bn254, proof, verifier_inputs);
+        let inputs = Groth16VerifierInputs::from_proof(proof, verifier_inputs);
+        // Verify proof
+        inputs.verify(&config)?;
         let input_json_file = std::fs::File::create(output_dir.to_path_buf().join(format!(
             "verifier_inputs_for_secret_{}_and_{}_pub_inputs{}.json",
             secret_value, N_PUB_INPUTS, list_of_pub_inputs_as_string
-        )))
-        .expect("failed to create and/or open the `verifier_input.json` file");
-        serde_json::to_writer(input_json_file, &inputs)
-            .expect("should be able to serialize the verifier config");
+        )))?;
+        serde_json::to_writer(input_json_file, &inputs).map_err(std::io::Error::from)?;
     }
+
+    Ok(())
 }
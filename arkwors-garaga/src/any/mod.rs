@@ -0,0 +1,238 @@
+//! Configurations and inputs whose curve is only known at runtime
+
+#[cfg(test)]
+mod tests;
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+
+use crate::{
+    AuditReport, ConfigurationDiff, ConfigurationReport, ElipticCurveId, GaragaError,
+    Groth16VerifierConfiguration, Groth16VerifierInputs, InputsReport,
+};
+
+/// A `Groth16VerifierConfiguration` over any of the curves Garaga supports
+///
+/// It deserializes to the variant named by the `eliptic_curve_id` field.
+// Not boxing the variants keeps them matchable, the configurations are not moved around much
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyGroth16VerifierConfiguration {
+    Bn254(Groth16VerifierConfiguration<Bn254>),
+    Bls12_381(Groth16VerifierConfiguration<Bls12_381>),
+}
+
+/// `Groth16VerifierInputs` over any of the curves Garaga supports
+///
+/// It deserializes to the variant named by the `eliptic_curve_id` field.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyGroth16VerifierInputs {
+    Bn254(Groth16VerifierInputs<Bn254>),
+    Bls12_381(Groth16VerifierInputs<Bls12_381>),
+}
+
+/// Run `$body` on the typed value held by `$any`, bound to `$value`
+macro_rules! dispatch {
+    ($any:ident, $value:ident => $body:expr) => {
+        match $any {
+            Self::Bn254($value) => $body,
+            Self::Bls12_381($value) => $body,
+        }
+    };
+}
+
+impl AnyGroth16VerifierConfiguration {
+    pub fn eliptic_curve_id(&self) -> ElipticCurveId {
+        dispatch!(self, config => config.eliptic_curve_id())
+    }
+
+    /// Same as `Groth16VerifierConfiguration::validate_points`
+    pub fn validate_points(&self) -> Result<(), GaragaError> {
+        dispatch!(self, config => config.validate_points())
+    }
+
+    /// Same as `Groth16VerifierConfiguration::audit`
+    pub fn audit(&self) -> AuditReport {
+        dispatch!(self, config => config.audit())
+    }
+
+    /// Same as `Groth16VerifierConfiguration::cairo_constants`
+    pub fn cairo_constants(&self) -> Result<String, GaragaError> {
+        dispatch!(self, config => config.cairo_constants())
+    }
+
+    /// Same as `Groth16VerifierConfiguration::inspect`
+    pub fn inspect(&self) -> ConfigurationReport {
+        dispatch!(self, config => config.inspect())
+    }
+
+    /// Same as `Groth16VerifierConfiguration::diff`, a configuration for another curve differs
+    /// in all its elements
+    pub fn diff(&self, other: &AnyGroth16VerifierConfiguration) -> ConfigurationDiff {
+        self.inspect().diff(&other.inspect())
+    }
+}
+
+impl AnyGroth16VerifierInputs {
+    pub fn eliptic_curve_id(&self) -> ElipticCurveId {
+        dispatch!(self, inputs => inputs.eliptic_curve_id())
+    }
+
+    /// Same as `Groth16VerifierInputs::validate_points`
+    pub fn validate_points(&self) -> Result<(), GaragaError> {
+        dispatch!(self, inputs => inputs.validate_points())
+    }
+
+    /// Same as `Groth16VerifierInputs::inspect`
+    pub fn inspect(&self) -> InputsReport {
+        dispatch!(self, inputs => inputs.inspect())
+    }
+
+    /// Same as `Groth16VerifierInputs::verify`, failing if `config` is for another curve
+    pub fn verify(&self, config: &AnyGroth16VerifierConfiguration) -> Result<(), GaragaError> {
+        match (self, config) {
+            (Self::Bn254(inputs), AnyGroth16VerifierConfiguration::Bn254(config)) => {
+                inputs.verify(config)
+            }
+            (Self::Bls12_381(inputs), AnyGroth16VerifierConfiguration::Bls12_381(config)) => {
+                inputs.verify(config)
+            }
+            _ => Err(curve_mismatch(config, self)),
+        }
+    }
+
+    /// Same as `Groth16VerifierInputs::proof_calldata`, failing if `config` is for another curve
+    #[cfg(feature = "calldata")]
+    pub fn proof_calldata(
+        &self,
+        config: &AnyGroth16VerifierConfiguration,
+    ) -> Result<Vec<crate::calldata::Felt>, GaragaError> {
+        match (self, config) {
+            (Self::Bn254(inputs), AnyGroth16VerifierConfiguration::Bn254(config)) => {
+                inputs.proof_calldata(config)
+            }
+            (Self::Bls12_381(inputs), AnyGroth16VerifierConfiguration::Bls12_381(config)) => {
+                inputs.proof_calldata(config)
+            }
+            _ => Err(curve_mismatch(config, self)),
+        }
+    }
+}
+
+fn curve_mismatch(
+    config: &AnyGroth16VerifierConfiguration,
+    inputs: &AnyGroth16VerifierInputs,
+) -> GaragaError {
+    GaragaError::CurveMismatch {
+        expected: config.eliptic_curve_id(),
+        found: inputs.eliptic_curve_id(),
+    }
+}
+
+impl From<Groth16VerifierConfiguration<Bn254>> for AnyGroth16VerifierConfiguration {
+    fn from(value: Groth16VerifierConfiguration<Bn254>) -> Self {
+        Self::Bn254(value)
+    }
+}
+
+impl From<Groth16VerifierConfiguration<Bls12_381>> for AnyGroth16VerifierConfiguration {
+    fn from(value: Groth16VerifierConfiguration<Bls12_381>) -> Self {
+        Self::Bls12_381(value)
+    }
+}
+
+impl From<Groth16VerifierInputs<Bn254>> for AnyGroth16VerifierInputs {
+    fn from(value: Groth16VerifierInputs<Bn254>) -> Self {
+        Self::Bn254(value)
+    }
+}
+
+impl From<Groth16VerifierInputs<Bls12_381>> for AnyGroth16VerifierInputs {
+    fn from(value: Groth16VerifierInputs<Bls12_381>) -> Self {
+        Self::Bls12_381(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod impl_serde {
+    use ark_bls12_381::Bls12_381;
+    use ark_bn254::Bn254;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{AnyGroth16VerifierConfiguration, AnyGroth16VerifierInputs};
+    use crate::{
+        serde::{
+            configuration_from_serde, inputs_from_serde, Proof as SerdeProof,
+            VerifyingKey as SerdeVerifyingKey,
+        },
+        ElipticCurveId,
+    };
+
+    #[derive(Deserialize)]
+    struct Configuration {
+        eliptic_curve_id: ElipticCurveId,
+        verifying_key: SerdeVerifyingKey,
+    }
+
+    #[derive(Deserialize)]
+    struct Inputs {
+        eliptic_curve_id: ElipticCurveId,
+        proof: SerdeProof,
+        public_inputs: Vec<String>,
+    }
+
+    impl Serialize for AnyGroth16VerifierConfiguration {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            dispatch!(self, config => config.serialize(serializer))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnyGroth16VerifierConfiguration {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let Configuration {
+                eliptic_curve_id,
+                verifying_key,
+            } = Configuration::deserialize(deserializer)?;
+
+            match eliptic_curve_id {
+                ElipticCurveId::Bn254 => {
+                    configuration_from_serde::<Bn254>(eliptic_curve_id, &verifying_key)
+                        .map(Self::Bn254)
+                }
+                ElipticCurveId::Bls12_381 => {
+                    configuration_from_serde::<Bls12_381>(eliptic_curve_id, &verifying_key)
+                        .map(Self::Bls12_381)
+                }
+            }
+            .map_err(de::Error::custom)
+        }
+    }
+
+    impl Serialize for AnyGroth16VerifierInputs {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            dispatch!(self, inputs => inputs.serialize(serializer))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for AnyGroth16VerifierInputs {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let Inputs {
+                eliptic_curve_id,
+                proof,
+                public_inputs,
+            } = Inputs::deserialize(deserializer)?;
+
+            match eliptic_curve_id {
+                ElipticCurveId::Bn254 => {
+                    inputs_from_serde::<Bn254>(eliptic_curve_id, &proof, &public_inputs)
+                        .map(Self::Bn254)
+                }
+                ElipticCurveId::Bls12_381 => {
+                    inputs_from_serde::<Bls12_381>(eliptic_curve_id, &proof, &public_inputs)
+                        .map(Self::Bls12_381)
+                }
+            }
+            .map_err(de::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,137 @@
+mod eliptic_curve_id {
+    use std::str::FromStr;
+
+    use assert_matches::assert_matches;
+
+    use crate::{ElipticCurveId, GaragaError};
+
+    #[test]
+    fn from_str_and_display() {
+        for id in [ElipticCurveId::Bn254, ElipticCurveId::Bls12_381] {
+            assert_eq!(ElipticCurveId::from_str(&id.to_string()).unwrap(), id);
+        }
+        assert_eq!(ElipticCurveId::Bls12_381.to_string(), "bls12_381");
+
+        assert_matches!(
+            ElipticCurveId::from_str("BN254"),
+            Err(GaragaError::InvalidElipticCurveId(id)) if id == "BN254"
+        );
+    }
+}
+
+mod any_groth_16_verifier_configuration {
+    use crate::{
+        test_utils::valid_proof, AnyGroth16VerifierConfiguration, ElipticCurveId,
+        Groth16VerifierConfiguration,
+    };
+
+    #[test]
+    fn forwards_to_typed_configuration() {
+        let (vk, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let any = AnyGroth16VerifierConfiguration::from(config.clone());
+
+        assert_eq!(any.eliptic_curve_id(), ElipticCurveId::Bls12_381);
+        any.validate_points().unwrap();
+        assert_eq!(any.audit(), config.audit());
+        assert_eq!(
+            any.cairo_constants().unwrap(),
+            config.cairo_constants().unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization() {
+        let (vk, _, _) = valid_proof::<ark_bn254::Bn254>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let json = serde_json::to_string(&config).unwrap();
+
+        let any = serde_json::from_str::<AnyGroth16VerifierConfiguration>(&json).unwrap();
+        assert_eq!(any, AnyGroth16VerifierConfiguration::Bn254(config));
+        assert_eq!(serde_json::to_string(&any).unwrap(), json);
+
+        let (vk, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(1);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            serde_json::from_str::<AnyGroth16VerifierConfiguration>(&json).unwrap(),
+            AnyGroth16VerifierConfiguration::Bls12_381(config)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_points_of_another_curve() {
+        let (vk, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(1);
+        let json = serde_json::to_string(&Groth16VerifierConfiguration::from_verifying_key(vk))
+            .unwrap()
+            .replace("bls12_381", "bn254");
+
+        assert!(serde_json::from_str::<AnyGroth16VerifierConfiguration>(&json).is_err());
+    }
+}
+
+mod any_groth_16_verifier_inputs {
+    use assert_matches::assert_matches;
+
+    use crate::{
+        test_utils::valid_proof, AnyGroth16VerifierConfiguration, AnyGroth16VerifierInputs,
+        ElipticCurveId, GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+
+    #[test]
+    fn verify() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(2);
+        let config = AnyGroth16VerifierConfiguration::from(
+            Groth16VerifierConfiguration::from_verifying_key(vk),
+        );
+        let inputs =
+            AnyGroth16VerifierInputs::from(Groth16VerifierInputs::from_proof(proof, public_inputs));
+        assert_eq!(inputs.eliptic_curve_id(), ElipticCurveId::Bn254);
+        inputs.validate_points().unwrap();
+        inputs.verify(&config).unwrap();
+
+        let (vk, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+        let other_config = AnyGroth16VerifierConfiguration::from(
+            Groth16VerifierConfiguration::from_verifying_key(vk),
+        );
+        assert_matches!(
+            inputs.verify(&other_config),
+            Err(GaragaError::CurveMismatch {
+                expected: ElipticCurveId::Bls12_381,
+                found: ElipticCurveId::Bn254
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "calldata")]
+    fn proof_calldata() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(1);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+
+        assert_eq!(
+            AnyGroth16VerifierInputs::from(inputs.clone())
+                .proof_calldata(&config.clone().into())
+                .unwrap(),
+            inputs.proof_calldata(&config).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization() {
+        let (_, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(3);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+        let json = serde_json::to_string(&inputs).unwrap();
+
+        let any = serde_json::from_str::<AnyGroth16VerifierInputs>(&json).unwrap();
+        assert_eq!(any, AnyGroth16VerifierInputs::Bls12_381(inputs));
+        assert_eq!(serde_json::to_string(&any).unwrap(), json);
+
+        let json = json.replace("bls12_381", "secp256k1");
+        assert!(serde_json::from_str::<AnyGroth16VerifierInputs>(&json).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use ark_ec::AffineRepr;
+
+use crate::{
+    check_point, GaragaCurve, GaragaError, Groth16VerifierConfiguration, InvalidPointReason,
+    PointId,
+};
+
+/// A red flag raised by `Groth16VerifierConfiguration::audit`
+///
+/// Any of them either allows proofs to be forged or shows the key was not produced
+/// by a proper trusted setup.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum AuditFinding {
+    #[error("`gamma_g2` and `delta_g2` are equal")]
+    GammaEqualsDelta,
+    #[error("`{0}` is the generator of its group, its discrete logarithm is known")]
+    Generator(PointId),
+    #[error("`{0}` is the point at infinity")]
+    AtInfinity(PointId),
+    #[error("`{point}` is invalid: {reason}")]
+    InvalidPoint {
+        point: PointId,
+        reason: InvalidPointReason,
+    },
+    #[error("the key has no `ic` point")]
+    NoIc,
+    #[error("`ic[{first}]` and `ic[{second}]` are equal")]
+    DuplicateIc { first: usize, second: usize },
+}
+
+/// The findings of `Groth16VerifierConfiguration::audit`, empty for a key that passed it
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AuditReport {
+    findings: Vec<AuditFinding>,
+}
+
+impl AuditReport {
+    pub fn findings(&self) -> &Vec<AuditFinding> {
+        &self.findings
+    }
+
+    pub fn passed(&self) -> bool {
+        self.findings.is_empty()
+    }
+}
+
+impl fmt::Display for AuditReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let findings = self
+            .findings
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", findings.join(", "))
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Look for known-unsafe setups in the verifying key
+    ///
+    /// It flags keys with `gamma_g2 == delta_g2`, points set to the generator of their group
+    /// or to the point at infinity (as in `VerifyingKey::default()`), invalid points,
+    /// a missing or duplicate `ic` point. Points of `ic` at infinity are legitimate, and so is
+    /// `gamma_g2` being the generator: snarkjs setups, RISC Zero's among them, leave it so,
+    /// only `delta_g2` has to hide its discrete logarithm.
+    pub fn audit(&self) -> AuditReport {
+        let vk = self.verifying_key();
+        let mut findings = Vec::new();
+
+        let mut audit_point = |is_zero: bool, is_generator: bool, point: PointId, check| {
+            if is_zero {
+                findings.push(AuditFinding::AtInfinity(point));
+            } else if let Err(GaragaError::InvalidPoint { point, reason }) = check {
+                findings.push(AuditFinding::InvalidPoint { point, reason });
+            } else if is_generator {
+                findings.push(AuditFinding::Generator(point));
+            }
+        };
+        audit_point(
+            vk.alpha_g1.is_zero(),
+            vk.alpha_g1 == E::G1Affine::generator(),
+            PointId::AlphaG1,
+            check_point(&vk.alpha_g1, PointId::AlphaG1),
+        );
+        for (value, point) in [
+            (&vk.beta_g2, PointId::BetaG2),
+            (&vk.gamma_g2, PointId::GammaG2),
+            (&vk.delta_g2, PointId::DeltaG2),
+        ] {
+            audit_point(
+                value.is_zero(),
+                point != PointId::GammaG2 && *value == E::G2Affine::generator(),
+                point,
+                check_point(value, point),
+            );
+        }
+        if vk.gamma_g2 == vk.delta_g2 {
+            findings.push(AuditFinding::GammaEqualsDelta);
+        }
+
+        if vk.gamma_abc_g1.is_empty() {
+            findings.push(AuditFinding::NoIc);
+        }
+        for (i, ic) in vk.gamma_abc_g1.iter().enumerate() {
+            if let Err(GaragaError::InvalidPoint { point, reason }) =
+                check_point(ic, PointId::Ic(i))
+            {
+                findings.push(AuditFinding::InvalidPoint { point, reason });
+            }
+            if let Some(first) = vk.gamma_abc_g1[..i]
+                .iter()
+                .position(|other| !ic.is_zero() && other == ic)
+            {
+                findings.push(AuditFinding::DuplicateIc { first, second: i });
+            }
+        }
+
+        AuditReport { findings }
+    }
+
+    /// Same as `audit`, failing with `GaragaError::UnsafeVerifyingKey` if the key didn't pass it
+    ///
+    /// It lets exports refuse unsafe keys: `serde_json::to_writer(file, config.audited()?)`.
+    pub fn audited(&self) -> Result<&Self, GaragaError> {
+        let report = self.audit();
+        if !report.passed() {
+            return Err(GaragaError::UnsafeVerifyingKey(report));
+        }
+
+        Ok(self)
+    }
+}
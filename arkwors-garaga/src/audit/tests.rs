@@ -0,0 +1,101 @@
+mod groth_16_verifier_configuration {
+    use ark_ec::AffineRepr;
+    use ark_groth16::VerifyingKey;
+    use assert_matches::assert_matches;
+
+    use crate::{
+        test_utils::{outside_subgroup, random_verifying_key},
+        AuditFinding, GaragaError, Groth16VerifierConfiguration, PointId,
+    };
+
+    #[test]
+    fn audit_passes_random_key() {
+        let mut vk = random_verifying_key::<ark_bn254::Bn254>(3);
+        // `ic` points at infinity are legitimate, even when repeated
+        vk.gamma_abc_g1.extend([ark_bn254::G1Affine::zero(); 2]);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+
+        let report = config.audit();
+        assert!(report.passed());
+        assert!(report.findings().is_empty());
+        assert_eq!(config.audited().unwrap(), &config);
+    }
+
+    #[test]
+    fn audit_flags_default_key() {
+        let config = Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<
+            ark_bls12_381::Bls12_381,
+        >::default());
+
+        assert_eq!(
+            config.audit().findings(),
+            &vec![
+                AuditFinding::AtInfinity(PointId::AlphaG1),
+                AuditFinding::AtInfinity(PointId::BetaG2),
+                AuditFinding::AtInfinity(PointId::GammaG2),
+                AuditFinding::AtInfinity(PointId::DeltaG2),
+                AuditFinding::GammaEqualsDelta,
+                AuditFinding::NoIc,
+            ]
+        );
+    }
+
+    #[test]
+    fn audit_flags_generators_and_duplicate_ic() {
+        let mut vk = random_verifying_key::<ark_bn254::Bn254>(4);
+        vk.alpha_g1 = ark_bn254::G1Affine::generator();
+        vk.delta_g2 = ark_bn254::G2Affine::generator();
+        vk.gamma_abc_g1[3] = vk.gamma_abc_g1[1];
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+
+        let report = config.audit();
+        assert!(!report.passed());
+        assert_eq!(
+            report.findings(),
+            &vec![
+                AuditFinding::Generator(PointId::AlphaG1),
+                AuditFinding::Generator(PointId::DeltaG2),
+                AuditFinding::DuplicateIc {
+                    first: 1,
+                    second: 3
+                },
+            ]
+        );
+        assert_eq!(
+            report.to_string(),
+            "`alpha_g1` is the generator of its group, its discrete logarithm is known, `delta_g2` is the generator of its group, its discrete logarithm is known, `ic[1]` and `ic[3]` are equal"
+        );
+        assert_matches!(
+            config.audited(),
+            Err(GaragaError::UnsafeVerifyingKey(r)) if r == report
+        );
+    }
+
+    #[test]
+    fn audit_accepts_gamma_generator() {
+        // As in every snarkjs key
+        let mut vk = random_verifying_key::<ark_bn254::Bn254>(2);
+        vk.gamma_g2 = ark_bn254::G2Affine::generator();
+
+        assert!(Groth16VerifierConfiguration::from_verifying_key(vk)
+            .audit()
+            .passed());
+    }
+
+    #[test]
+    fn audit_flags_invalid_points() {
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<ark_bn254::Bn254> {
+                delta_g2: outside_subgroup::<ark_bn254::g2::Config>(),
+                ..random_verifying_key(2)
+            });
+
+        assert_eq!(
+            config.audit().findings(),
+            &vec![AuditFinding::InvalidPoint {
+                point: PointId::DeltaG2,
+                reason: crate::InvalidPointReason::NotInSubgroup
+            }]
+        );
+    }
+}
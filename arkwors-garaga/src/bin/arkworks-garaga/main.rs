@@ -0,0 +1,303 @@
+//! Convert Groth16 verifying keys and proofs into the `.json` files Garaga expects,
+//! check proofs against keys, print the calldata of proofs and write the Scarb packages of verifiers
+//!
+//! * Usage
+//! ```shell
+//! cargo run --features cli -- config --curve bn254 --format snarkjs verification_key.json -o verifier_config.json
+//! cargo run --features cli -- inputs --curve bn254 --format snarkjs proof.json public.json -o verifier_inputs.json
+//! cargo run --features cli -- verify verifier_config.json verifier_inputs.json
+//! cargo run --features cli -- proof-calldata verifier_config.json verifier_inputs.json
+//! cargo run --features cli -- scarb verifier_config.json --name my_verifier --ecip-ops-class-hash 0x684d2756a4440c190a5fe54e367c0abe33aefa75084dec2fffc791b620c80e3 -o my_verifier
+//! cargo run --features cli -- inspect verifier_config.json
+//! cargo run --features cli -- diff old_verifier_config.json verifier_config.json
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_groth16::{Proof, VerifyingKey};
+use ark_serialize::CanonicalDeserialize;
+use arkwors_garaga::{
+    scarb::ScarbProject,
+    snarkjs::{SnarkjsProof, SnarkjsPublicInputs, SnarkjsVerifyingKey},
+    AnyGroth16VerifierConfiguration, AnyGroth16VerifierInputs, ElipticCurveId, GaragaCurve,
+    GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use serde::{de::DeserializeOwned, Serialize};
+
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Write the `verifier_config.json` of a verifying key, refusing keys that don't pass the audit
+    Config {
+        /// The curve of the key, `bn254` or `bls12_381`
+        #[arg(long)]
+        curve: ElipticCurveId,
+        #[arg(long, value_enum)]
+        format: Format,
+        /// The verifying key
+        vk: PathBuf,
+        /// Where to write the `.json`, the standard output by default
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Write the `verifier_inputs.json` of a proof and its public inputs
+    Inputs {
+        /// The curve of the proof, `bn254` or `bls12_381`
+        #[arg(long)]
+        curve: ElipticCurveId,
+        #[arg(long, value_enum)]
+        format: Format,
+        /// The proof
+        proof: PathBuf,
+        /// The public inputs, a `public.json` or a `Vec` of scalars in the Arkworks formats
+        public_inputs: PathBuf,
+        /// Where to write the `.json`, the standard output by default
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Check the proof of a `verifier_inputs.json` against the key of a `verifier_config.json`
+    Verify { config: PathBuf, inputs: PathBuf },
+    /// Print the serialized proof and public inputs of a `verifier_inputs.json`, one decimal
+    /// `felt252` per line
+    ///
+    /// It is only the start of the calldata of Garaga's verifiers: the hints they expect after it
+    /// are not computed.
+    ProofCalldata { config: PathBuf, inputs: PathBuf },
+    /// Write the Scarb package of the verifier contract of a `verifier_config.json`,
+    /// refusing keys that don't pass the audit
+    Scarb {
+        config: PathBuf,
+        /// The name of the package
+        #[arg(long)]
+        name: String,
+        /// The class hash of the Garaga ECIP ops contract declared on the target network
+        #[arg(long)]
+        ecip_ops_class_hash: String,
+        /// The Garaga release to depend on, `scarb::GARAGA_VERSION` by default
+        #[arg(long)]
+        garaga_version: Option<String>,
+        /// The directory to write the package into
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Print the curve, the public inputs and the fingerprints of the points of a
+    /// `verifier_config.json` or a `verifier_inputs.json`
+    Inspect { file: PathBuf },
+    /// Print which elements of the key of a `verifier_config.json` differ in another one
+    Diff { old: PathBuf, new: PathBuf },
+}
+
+/// The format of the keys and proofs to convert
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Format {
+    /// Arkworks' compressed `CanonicalSerialize` output
+    ArkCompressed,
+    /// Arkworks' uncompressed `CanonicalSerialize` output
+    ArkUncompressed,
+    /// snarkjs' `verification_key.json`, `proof.json` and `public.json`
+    Snarkjs,
+}
+
+/// I/O errors stay `GaragaError::Io`, syntax and data errors become `GaragaError::InvalidJson`
+fn json_error(error: serde_json::Error) -> GaragaError {
+    if error.is_io() {
+        io::Error::from(error).into()
+    } else {
+        GaragaError::InvalidJson(error.to_string())
+    }
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, GaragaError> {
+    serde_json::from_reader(File::open(path)?).map_err(json_error)
+}
+
+fn read_ark<T: CanonicalDeserialize>(path: &Path, format: Format) -> Result<T, GaragaError> {
+    let file = File::open(path)?;
+
+    Ok(match format {
+        Format::ArkUncompressed => T::deserialize_uncompressed(file)?,
+        _ => T::deserialize_compressed(file)?,
+    })
+}
+
+/// Write `value` as `.json` to `output`, or to `out` if there is none
+fn write_json(
+    value: &impl Serialize,
+    output: Option<&Path>,
+    out: &mut impl Write,
+) -> Result<(), GaragaError> {
+    match output {
+        Some(path) => {
+            serde_json::to_writer_pretty(File::create(path)?, value).map_err(json_error)?
+        }
+        None => {
+            serde_json::to_writer_pretty(&mut *out, value).map_err(json_error)?;
+            writeln!(out)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn config<E: GaragaCurve>(
+    format: Format,
+    vk: &Path,
+) -> Result<Groth16VerifierConfiguration<E>, GaragaError> {
+    let config = match format {
+        Format::Snarkjs => {
+            Groth16VerifierConfiguration::from_snarkjs(&read_json::<SnarkjsVerifyingKey>(vk)?)?
+        }
+        _ => Groth16VerifierConfiguration::from_verifying_key(read_ark::<VerifyingKey<E>>(
+            vk, format,
+        )?),
+    };
+    config.validate_points()?;
+    config.audited()?;
+
+    Ok(config)
+}
+
+fn inputs<E: GaragaCurve>(
+    format: Format,
+    proof: &Path,
+    public_inputs: &Path,
+) -> Result<Groth16VerifierInputs<E>, GaragaError> {
+    let inputs = match format {
+        Format::Snarkjs => Groth16VerifierInputs::from_snarkjs(
+            &read_json::<SnarkjsProof>(proof)?,
+            &read_json::<SnarkjsPublicInputs>(public_inputs)?,
+        )?,
+        _ => Groth16VerifierInputs::from_proof(
+            read_ark::<Proof<E>>(proof, format)?,
+            read_ark::<Vec<E::ScalarField>>(public_inputs, format)?,
+        ),
+    };
+    inputs.validate_points()?;
+
+    Ok(inputs)
+}
+
+fn run(command: Command, out: &mut impl Write) -> Result<(), GaragaError> {
+    match command {
+        Command::Config {
+            curve,
+            format,
+            vk,
+            output,
+        } => {
+            let config: AnyGroth16VerifierConfiguration = match curve {
+                ElipticCurveId::Bn254 => config::<Bn254>(format, &vk)?.into(),
+                ElipticCurveId::Bls12_381 => config::<Bls12_381>(format, &vk)?.into(),
+            };
+            write_json(&config, output.as_deref(), out)
+        }
+        Command::Inputs {
+            curve,
+            format,
+            proof,
+            public_inputs,
+            output,
+        } => {
+            let inputs: AnyGroth16VerifierInputs = match curve {
+                ElipticCurveId::Bn254 => inputs::<Bn254>(format, &proof, &public_inputs)?.into(),
+                ElipticCurveId::Bls12_381 => {
+                    inputs::<Bls12_381>(format, &proof, &public_inputs)?.into()
+                }
+            };
+            write_json(&inputs, output.as_deref(), out)
+        }
+        Command::Verify { config, inputs } => {
+            let config: AnyGroth16VerifierConfiguration = read_json(&config)?;
+            let inputs: AnyGroth16VerifierInputs = read_json(&inputs)?;
+            inputs.verify(&config)?;
+
+            writeln!(out, "the proof is valid")?;
+            Ok(())
+        }
+        Command::ProofCalldata { config, inputs } => {
+            let config: AnyGroth16VerifierConfiguration = read_json(&config)?;
+            let inputs: AnyGroth16VerifierInputs = read_json(&inputs)?;
+
+            for felt in inputs.proof_calldata(&config)? {
+                writeln!(out, "{felt}")?;
+            }
+            Ok(())
+        }
+        Command::Scarb {
+            config,
+            name,
+            ecip_ops_class_hash,
+            garaga_version,
+            output,
+        } => {
+            let mut project = ScarbProject::new(&name, &ecip_ops_class_hash)?;
+            if let Some(garaga_version) = garaga_version {
+                project = project.with_garaga_version(&garaga_version);
+            }
+            match read_json(&config)? {
+                AnyGroth16VerifierConfiguration::Bn254(config) => {
+                    project.write(config.audited()?, &output)?
+                }
+                AnyGroth16VerifierConfiguration::Bls12_381(config) => {
+                    project.write(config.audited()?, &output)?
+                }
+            }
+
+            writeln!(out, "wrote the `{name}` package to {}", output.display())?;
+            Ok(())
+        }
+        Command::Inspect { file } => {
+            let value: serde_json::Value = read_json(&file)?;
+            // Only configurations have a verifying key
+            if value.get("verifying_key").is_some() {
+                let config: AnyGroth16VerifierConfiguration =
+                    serde_json::from_value(value).map_err(json_error)?;
+                write!(out, "{}", config.inspect())?;
+            } else {
+                let inputs: AnyGroth16VerifierInputs =
+                    serde_json::from_value(value).map_err(json_error)?;
+                write!(out, "{}", inputs.inspect())?;
+            }
+            Ok(())
+        }
+        Command::Diff { old, new } => {
+            let old: AnyGroth16VerifierConfiguration = read_json(&old)?;
+            let new: AnyGroth16VerifierConfiguration = read_json(&new)?;
+
+            let diff = old.diff(&new);
+            if diff.is_empty() {
+                writeln!(out, "the verifying keys are the same")?;
+            } else {
+                write!(out, "{diff}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse().command, &mut io::stdout().lock()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,332 @@
+use std::{fs, path::PathBuf};
+
+use super::{run, Command, Format};
+
+/// A snarkjs key, proof and public inputs, the ones the library's tests use
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("fixtures/snarkjs")
+        .join(name)
+}
+
+/// A fresh directory to write files into
+fn output_dir(name: &str) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("arkworks-garaga-cli-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Run `command`, returning what it printed
+fn output(command: Command) -> Result<String, arkwors_garaga::GaragaError> {
+    let mut out = Vec::new();
+    run(command, &mut out)?;
+
+    Ok(String::from_utf8(out).unwrap())
+}
+
+mod convert {
+    use std::fs::{self, File};
+
+    use ark_serialize::CanonicalSerialize;
+    use arkwors_garaga::{
+        snarkjs::{SnarkjsProof, SnarkjsVerifyingKey},
+        ElipticCurveId, GaragaError,
+    };
+    use assert_matches::assert_matches;
+
+    use super::{fixture, output, output_dir, Command, Format};
+
+    #[test]
+    fn snarkjs_and_ark_files_convert_alike() {
+        let dir = output_dir("convert");
+        let snarkjs_config = output(Command::Config {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            vk: fixture("verification_key.json"),
+            output: None,
+        })
+        .unwrap();
+        let snarkjs_inputs = output(Command::Inputs {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            proof: fixture("proof.json"),
+            public_inputs: fixture("public.json"),
+            output: None,
+        })
+        .unwrap();
+
+        // The same key, proof and public inputs, serialized by Arkworks
+        let vk: SnarkjsVerifyingKey =
+            serde_json::from_reader(File::open(fixture("verification_key.json")).unwrap()).unwrap();
+        let proof: SnarkjsProof =
+            serde_json::from_reader(File::open(fixture("proof.json")).unwrap()).unwrap();
+        let public_inputs: Vec<String> =
+            serde_json::from_reader(File::open(fixture("public.json")).unwrap()).unwrap();
+        let public_inputs =
+            arkwors_garaga::snarkjs::public_inputs_from_snarkjs::<ark_bn254::Fr>(&public_inputs)
+                .unwrap();
+        for (format, compress) in [
+            (Format::ArkCompressed, ark_serialize::Compress::Yes),
+            (Format::ArkUncompressed, ark_serialize::Compress::No),
+        ] {
+            let write = |name: &str, value: &dyn Fn(File)| {
+                let path = dir.join(name);
+                value(File::create(&path).unwrap());
+                path
+            };
+            let vk = write("vk.bin", &|file| {
+                vk.to_ark::<ark_bn254::Bn254>()
+                    .unwrap()
+                    .serialize_with_mode(file, compress)
+                    .unwrap()
+            });
+            let proof = write("proof.bin", &|file| {
+                proof
+                    .to_ark::<ark_bn254::Bn254>()
+                    .unwrap()
+                    .serialize_with_mode(file, compress)
+                    .unwrap()
+            });
+            let public_inputs = write("public.bin", &|file| {
+                public_inputs.serialize_with_mode(file, compress).unwrap()
+            });
+
+            let config = output(Command::Config {
+                curve: ElipticCurveId::Bn254,
+                format,
+                vk,
+                output: None,
+            })
+            .unwrap();
+            let inputs = output(Command::Inputs {
+                curve: ElipticCurveId::Bn254,
+                format,
+                proof,
+                public_inputs,
+                output: None,
+            })
+            .unwrap();
+
+            assert_eq!(config, snarkjs_config);
+            assert_eq!(inputs, snarkjs_inputs);
+        }
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn refuses_files_of_other_curves() {
+        assert_matches!(
+            output(Command::Config {
+                curve: ElipticCurveId::Bls12_381,
+                format: Format::Snarkjs,
+                vk: fixture("verification_key.json"),
+                output: None,
+            }),
+            Err(GaragaError::CurveMismatch {
+                expected: ElipticCurveId::Bls12_381,
+                found: ElipticCurveId::Bn254
+            })
+        );
+    }
+}
+
+mod check {
+    use std::fs;
+
+    use arkwors_garaga::{ElipticCurveId, GaragaError};
+    use assert_matches::assert_matches;
+
+    use super::{fixture, output, output_dir, Command, Format};
+
+    #[test]
+    fn verify_and_proof_calldata() {
+        let dir = output_dir("check");
+        let (config, inputs) = (
+            dir.join("verifier_config.json"),
+            dir.join("verifier_inputs.json"),
+        );
+        output(Command::Config {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            vk: fixture("verification_key.json"),
+            output: Some(config.clone()),
+        })
+        .unwrap();
+        output(Command::Inputs {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            proof: fixture("proof.json"),
+            public_inputs: fixture("public.json"),
+            output: Some(inputs.clone()),
+        })
+        .unwrap();
+
+        assert_eq!(
+            output(Command::Verify {
+                config: config.clone(),
+                inputs: inputs.clone()
+            })
+            .unwrap(),
+            "the proof is valid\n"
+        );
+        let calldata = output(Command::ProofCalldata {
+            config: config.clone(),
+            inputs: inputs.clone(),
+        })
+        .unwrap();
+        let felts = calldata.lines().collect::<Vec<_>>();
+        // `a`, `b` and `c` as `u384`s, then the number of public inputs and the `u256` of the one
+        assert_eq!(felts.len(), 8 + 16 + 8 + 1 + 2);
+        assert_eq!(felts[32], "1");
+
+        // Another public input
+        let tampered = fs::read_to_string(&inputs).unwrap().replacen(
+            "\"public_inputs\": [\n    \"0x",
+            "\"public_inputs\": [\n    \"0x1",
+            1,
+        );
+        fs::write(&inputs, tampered).unwrap();
+        assert_matches!(
+            output(Command::Verify { config, inputs }),
+            Err(GaragaError::PairingCheckFailed)
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
+
+mod scarb {
+    use std::fs;
+
+    use arkwors_garaga::ElipticCurveId;
+
+    use super::{fixture, output, output_dir, Command, Format};
+
+    #[test]
+    fn writes_the_package() {
+        let dir = output_dir("scarb");
+        let config = dir.join("verifier_config.json");
+        output(Command::Config {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            vk: fixture("verification_key.json"),
+            output: Some(config.clone()),
+        })
+        .unwrap();
+
+        let package = dir.join("my_verifier");
+        assert_eq!(
+            output(Command::Scarb {
+                config,
+                name: "my_verifier".to_string(),
+                ecip_ops_class_hash:
+                    "0x684d2756a4440c190a5fe54e367c0abe33aefa75084dec2fffc791b620c80e3".to_string(),
+                garaga_version: Some("v0.15.4".to_string()),
+                output: package.clone(),
+            })
+            .unwrap(),
+            format!("wrote the `my_verifier` package to {}\n", package.display())
+        );
+        let manifest = fs::read_to_string(package.join("Scarb.toml")).unwrap();
+        assert!(manifest.contains("name = \"my_verifier\"") && manifest.contains("v0.15.4"));
+        assert!(package
+            .join("src/groth16_verifier_constants.cairo")
+            .is_file());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
+
+mod inspect {
+    use std::fs;
+
+    use arkwors_garaga::{ElipticCurveId, GaragaError};
+    use assert_matches::assert_matches;
+
+    use super::{fixture, output, output_dir, Command, Format};
+
+    #[test]
+    fn inspect_and_diff() {
+        let dir = output_dir("inspect");
+        let (config, other_config, inputs) = (
+            dir.join("verifier_config.json"),
+            dir.join("other_verifier_config.json"),
+            dir.join("verifier_inputs.json"),
+        );
+        output(Command::Config {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            vk: fixture("verification_key.json"),
+            output: Some(config.clone()),
+        })
+        .unwrap();
+        output(Command::Inputs {
+            curve: ElipticCurveId::Bn254,
+            format: Format::Snarkjs,
+            proof: fixture("proof.json"),
+            public_inputs: fixture("public.json"),
+            output: Some(inputs.clone()),
+        })
+        .unwrap();
+
+        let config_report = output(Command::Inspect {
+            file: config.clone(),
+        })
+        .unwrap();
+        assert!(config_report.starts_with("curve: bn254\npublic inputs: 1\n"));
+        assert!(config_report.ends_with(" (public input 0)\n"));
+        let inputs_report = output(Command::Inspect { file: inputs }).unwrap();
+        assert!(inputs_report.contains("\nc: ") && inputs_report.ends_with(" (ic[1])\n"));
+
+        assert_eq!(
+            output(Command::Diff {
+                old: config.clone(),
+                new: config.clone()
+            })
+            .unwrap(),
+            "the verifying keys are the same\n"
+        );
+
+        // Another `delta_g2` and no public input
+        let mut value: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&config).unwrap()).unwrap();
+        let vk = &mut value["verifying_key"];
+        vk["delta_g2"] = vk["beta_g2"].clone();
+        vk["ic"].as_array_mut().unwrap().pop();
+        fs::write(&other_config, value.to_string()).unwrap();
+        assert_eq!(
+            output(Command::Diff {
+                old: config,
+                new: other_config
+            })
+            .unwrap(),
+            "public inputs: 1 -> 0\nchanged: delta_g2\nremoved: ic[1]\n"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+    #[test]
+    fn invalid_json() {
+        let dir = output_dir("invalid-json");
+        let (not_json, not_config) = (dir.join("not_json.json"), dir.join("not_config.json"));
+        fs::write(&not_json, "{").unwrap();
+        fs::write(&not_config, r#"{"verifying_key": 1}"#).unwrap();
+
+        for file in [not_json, not_config] {
+            assert_matches!(
+                output(Command::Inspect { file }),
+                Err(GaragaError::InvalidJson(_))
+            );
+        }
+        assert_matches!(
+            output(Command::Inspect {
+                file: dir.join("missing.json")
+            }),
+            Err(GaragaError::Io(_))
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests;
+
+use ark_ec::pairing::Pairing;
+
+use crate::{
+    check_public_input_count, GaragaCurve, GaragaError, Groth16VerifierConfiguration,
+    Groth16VerifierInputs,
+};
+
+/// A verifier configuration and the inputs of a proof for it, as one self-contained artifact
+///
+/// It serializes to a single document holding the fields of both:
+///
+/// ```json
+/// {
+///     "eliptic_curve_id": "bn254",
+///     "verifying_key": { "alpha_g1": .., "beta_g2": .., "gamma_g2": .., "delta_g2": .., "ic": [..] },
+///     "proof": { "a": .., "b": .., "c": .. },
+///     "public_inputs": [..]
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Groth16ProofBundle<E: Pairing> {
+    config: Groth16VerifierConfiguration<E>,
+    inputs: Groth16VerifierInputs<E>,
+}
+
+impl<E: GaragaCurve> Groth16ProofBundle<E> {
+    /// Fails if `config` and `inputs` are not for the same curve,
+    /// or if the number of public inputs doesn't match the verifying key
+    pub fn new(
+        config: Groth16VerifierConfiguration<E>,
+        inputs: Groth16VerifierInputs<E>,
+    ) -> Result<Self, GaragaError> {
+        if config.eliptic_curve_id() != inputs.eliptic_curve_id() {
+            return Err(GaragaError::CurveMismatch {
+                expected: config.eliptic_curve_id(),
+                found: inputs.eliptic_curve_id(),
+            });
+        }
+        check_public_input_count(config.verifying_key(), inputs.public_inputs().len())?;
+
+        Ok(Self { config, inputs })
+    }
+
+    /// Same as `Groth16VerifierInputs::verify`, against the bundled configuration
+    pub fn verify(&self) -> Result<(), GaragaError> {
+        self.inputs.verify(&self.config)
+    }
+
+    /// Same as `Groth16VerifierInputs::proof_calldata`, for the bundled configuration
+    #[cfg(feature = "calldata")]
+    pub fn proof_calldata(&self) -> Result<Vec<crate::calldata::Felt>, GaragaError> {
+        self.inputs.proof_calldata(&self.config)
+    }
+}
+
+impl<E: Pairing> Groth16ProofBundle<E> {
+    pub fn config(&self) -> &Groth16VerifierConfiguration<E> {
+        &self.config
+    }
+
+    pub fn inputs(&self) -> &Groth16VerifierInputs<E> {
+        &self.inputs
+    }
+
+    pub fn into_parts(self) -> (Groth16VerifierConfiguration<E>, Groth16VerifierInputs<E>) {
+        (self.config, self.inputs)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod impl_serde {
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Groth16ProofBundle;
+    use crate::{
+        check_eliptic_curve_id,
+        serde::{
+            configuration_from_serde, inputs_from_serde, public_inputs_to_hex, Proof as SerdeProof,
+            VerifyingKey as SerdeVerifyingKey,
+        },
+        ElipticCurveId, GaragaCurve, GaragaError,
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct Bundle {
+        eliptic_curve_id: ElipticCurveId,
+        verifying_key: SerdeVerifyingKey,
+        proof: SerdeProof,
+        public_inputs: Vec<String>,
+    }
+
+    impl<E: GaragaCurve> TryFrom<&Groth16ProofBundle<E>> for Bundle {
+        type Error = GaragaError;
+
+        fn try_from(value: &Groth16ProofBundle<E>) -> Result<Self, Self::Error> {
+            Ok(Self {
+                eliptic_curve_id: value.config.eliptic_curve_id(),
+                verifying_key: SerdeVerifyingKey::try_from(value.config.verifying_key())?,
+                proof: SerdeProof::try_from(value.inputs.proof())?,
+                public_inputs: public_inputs_to_hex(value.inputs.public_inputs()),
+            })
+        }
+    }
+
+    impl Bundle {
+        fn to_ark<E: GaragaCurve>(&self) -> Result<Groth16ProofBundle<E>, GaragaError> {
+            check_eliptic_curve_id::<E>(self.eliptic_curve_id)?;
+
+            Groth16ProofBundle::new(
+                configuration_from_serde(self.eliptic_curve_id, &self.verifying_key)?,
+                inputs_from_serde(self.eliptic_curve_id, &self.proof, &self.public_inputs)?,
+            )
+        }
+    }
+
+    impl<E: GaragaCurve> Serialize for Groth16ProofBundle<E> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let bundle = Bundle::try_from(self).map_err(ser::Error::custom)?;
+            bundle.serialize(serializer)
+        }
+    }
+
+    impl<'de, E: GaragaCurve> Deserialize<'de> for Groth16ProofBundle<E> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let bundle = Bundle::deserialize(deserializer)?;
+            bundle.to_ark::<E>().map_err(de::Error::custom)
+        }
+    }
+}
@@ -0,0 +1,119 @@
+mod groth_16_proof_bundle {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use assert_matches::assert_matches;
+
+    use crate::{
+        test_utils::valid_proof, ElipticCurveId, GaragaError, Groth16ProofBundle,
+        Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+
+    #[test]
+    fn new_and_getters() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+
+        let bundle = Groth16ProofBundle::new(config.clone(), inputs.clone()).unwrap();
+        assert_eq!(bundle.config(), &config);
+        assert_eq!(bundle.inputs(), &inputs);
+        bundle.verify().unwrap();
+        assert_eq!(bundle.into_parts(), (config, inputs));
+    }
+
+    #[test]
+    fn new_rejects_public_input_count_mismatch() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs[1..].to_vec());
+
+        assert_matches!(
+            Groth16ProofBundle::new(config, inputs),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn verify_rejects_invalid_proof() {
+        let (vk, mut proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(1);
+        proof.a = (proof.a + ark_bn254::G1Affine::generator()).into_affine();
+        let bundle = Groth16ProofBundle::new(
+            Groth16VerifierConfiguration::from_verifying_key(vk),
+            Groth16VerifierInputs::from_proof(proof, public_inputs),
+        )
+        .unwrap();
+
+        assert_matches!(bundle.verify(), Err(GaragaError::PairingCheckFailed));
+    }
+
+    #[test]
+    #[cfg(feature = "calldata")]
+    fn proof_calldata() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(3);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+
+        let bundle = Groth16ProofBundle::new(config.clone(), inputs.clone()).unwrap();
+        assert_eq!(
+            bundle.proof_calldata().unwrap(),
+            inputs.proof_calldata(&config).unwrap()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialization() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+        let bundle = Groth16ProofBundle::new(config.clone(), inputs.clone()).unwrap();
+
+        let json = serde_json::to_value(&bundle).unwrap();
+        let config_json = serde_json::to_value(&config).unwrap();
+        let inputs_json = serde_json::to_value(&inputs).unwrap();
+        assert_eq!(json["eliptic_curve_id"], "bls12_381");
+        assert_eq!(json["verifying_key"], config_json["verifying_key"]);
+        assert_eq!(json["proof"], inputs_json["proof"]);
+        assert_eq!(json["public_inputs"], inputs_json["public_inputs"]);
+
+        assert_eq!(
+            serde_json::from_value::<Groth16ProofBundle<ark_bls12_381::Bls12_381>>(json).unwrap(),
+            bundle
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_inconsistent_bundle() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(2);
+        let bundle = Groth16ProofBundle::new(
+            Groth16VerifierConfiguration::from_verifying_key(vk),
+            Groth16VerifierInputs::from_proof(proof, public_inputs),
+        )
+        .unwrap();
+
+        let mut json = serde_json::to_value(&bundle).unwrap();
+        json["public_inputs"].as_array_mut().unwrap().pop();
+        let err = serde_json::from_value::<Groth16ProofBundle<ark_bn254::Bn254>>(json.clone())
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "wrong number of public inputs: expected 2, found 1"
+        );
+
+        let err = serde_json::from_value::<Groth16ProofBundle<ark_bls12_381::Bls12_381>>(
+            serde_json::to_value(&bundle).unwrap(),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "eliptic curve mismatch: expected {:?}, found {:?}",
+                ElipticCurveId::Bls12_381,
+                ElipticCurveId::Bn254
+            )
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Cairo code generation, formatted like the code Garaga's generator outputs
+
+#[cfg(test)]
+mod tests;
+
+use ark_ec::AffineRepr;
+use ark_ff::{Field, PrimeField, Zero};
+use num_bigint::BigUint;
+
+use crate::{
+    check_public_input_count, ElipticCurveId, G2Line, GaragaCurve, GaragaError,
+    Groth16VerifierConfiguration, GtElement,
+};
+
+/// Garaga splits big integers into limbs of 96 bits, the size of Cairo's `u96`
+const LIMB_BITS: u64 = 96;
+
+/// Little endian limbs of `value`, as laid out by the Cairo `u384` (N = 4) and `u288` (N = 3)
+pub(crate) fn limbs<const N: usize>(value: &BigUint) -> [BigUint; N] {
+    let mask = (BigUint::from(1u8) << LIMB_BITS) - 1u8;
+
+    std::array::from_fn(|i| (value >> (LIMB_BITS * i as u64)) & &mask)
+}
+
+/// A `u384 { limb0: 0x.., .. }` (N = 4) or `u288 { limb0: 0x.., .. }` (N = 3) literal
+fn uint_literal<F: PrimeField, const N: usize>(value: &F) -> String {
+    let limbs = limbs::<N>(&(*value).into())
+        .iter()
+        .enumerate()
+        .map(|(i, limb)| format!("limb{i}: {limb:#x}"))
+        .collect::<Vec<_>>();
+
+    format!("u{} {{ {} }}", N * LIMB_BITS as usize, limbs.join(", "))
+}
+
+/// The literal of a coordinate of a point, always a `u384`
+fn coordinate_literal<F: PrimeField>(value: &F) -> String {
+    uint_literal::<_, 4>(value)
+}
+
+/// The literal of a precomputed value, a `u288` for BN254 whose field elements fit in it
+fn precomputed_literal<E: GaragaCurve>(value: &E::BaseField) -> String {
+    match E::ELIPTIC_CURVE_ID {
+        ElipticCurveId::Bn254 => uint_literal::<_, 3>(value),
+        ElipticCurveId::Bls12_381 => uint_literal::<_, 4>(value),
+    }
+}
+
+/// A struct literal made of `fields`, whose closing brace is at the given indentation level
+fn struct_literal(name: &str, fields: &[(String, String)], indent: usize) -> String {
+    let pad = "    ".repeat(indent);
+    let fields = fields
+        .iter()
+        .map(|(field, value)| format!("{pad}    {field}: {value},\n"))
+        .collect::<String>();
+
+    format!("{name} {{\n{fields}{pad}}}")
+}
+
+/// The fields `{name}0`, `{name}1`, ... holding the base prime field coordinates of `value`
+fn extension_field_fields<F: Field>(
+    name: &str,
+    value: &F,
+    literal: impl Fn(&F::BasePrimeField) -> String,
+) -> Vec<(String, String)> {
+    value
+        .to_base_prime_field_elements()
+        .enumerate()
+        .map(|(i, c)| (format!("{name}{i}"), literal(&c)))
+        .collect()
+}
+
+/// The point at infinity, which only `ic` may hold, is written `(0, 0)` as Garaga encodes it
+fn g1_point_literal<E: GaragaCurve>(point: &E::G1Affine, indent: usize) -> String {
+    let (x, y) = point
+        .xy()
+        .map_or((Zero::zero(), Zero::zero()), |(x, y)| (*x, *y));
+    let fields = [
+        ("x".to_string(), coordinate_literal(&x)),
+        ("y".to_string(), coordinate_literal(&y)),
+    ];
+
+    struct_literal("G1Point", &fields, indent)
+}
+
+fn g2_point_literal<E: GaragaCurve>(point: &E::G2Affine, indent: usize) -> String {
+    let (x, y) = point
+        .xy()
+        .map_or((Zero::zero(), Zero::zero()), |(x, y)| (*x, *y));
+    let mut fields = extension_field_fields("x", &x, coordinate_literal);
+    fields.extend(extension_field_fields("y", &y, coordinate_literal));
+
+    struct_literal("G2Point", &fields, indent)
+}
+
+fn g2_line_literal<E: GaragaCurve>(line: &G2Line<E>, indent: usize) -> String {
+    let mut fields = extension_field_fields("r0a", line.r0(), precomputed_literal::<E>);
+    fields.extend(extension_field_fields(
+        "r1a",
+        line.r1(),
+        precomputed_literal::<E>,
+    ));
+
+    struct_literal("G2Line", &fields, indent)
+}
+
+fn e12d_literal<E: GaragaCurve>(value: &GtElement<E>, indent: usize) -> String {
+    let fields = value
+        .coefficients()
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (format!("w{i}"), precomputed_literal::<E>(c)))
+        .collect::<Vec<_>>();
+
+    struct_literal("E12D", &fields, indent)
+}
+
+/// An array constant, one element per line
+fn array_constant(name: &str, element_type: &str, elements: &[String]) -> String {
+    let count = elements.len();
+    let elements = elements
+        .iter()
+        .map(|element| format!("    {element},\n"))
+        .collect::<String>();
+
+    format!("pub const {name}: [{element_type}; {count}] = [\n{elements}];\n")
+}
+
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// The `groth16_verifier_constants.cairo` module of a Garaga verifier for this verifying key
+    ///
+    /// It holds the verifying key, with the precomputed Miller loop result of `alpha_g1` and
+    /// `beta_g2`, the `ic` points and the precomputed lines of `gamma_g2` and `delta_g2`.
+    pub fn cairo_constants(&self) -> Result<String, GaragaError> {
+        let vk = self.verifying_key();
+        check_public_input_count(vk, vk.gamma_abc_g1.len().saturating_sub(1))?;
+        let miller_loop_result = self.alpha_beta_miller_loop_result()?;
+        let precomputed_lines = self.precomputed_lines()?.interleaved();
+
+        let integer_types = match E::ELIPTIC_CURVE_ID {
+            ElipticCurveId::Bn254 => "u288, u384",
+            ElipticCurveId::Bls12_381 => "u384",
+        };
+        let verifying_key = struct_literal(
+            "Groth16VerifyingKey",
+            &[
+                (
+                    "alpha_beta_miller_loop_result".to_string(),
+                    e12d_literal(&miller_loop_result, 1),
+                ),
+                (
+                    "gamma_g2".to_string(),
+                    g2_point_literal::<E>(&vk.gamma_g2, 1),
+                ),
+                (
+                    "delta_g2".to_string(),
+                    g2_point_literal::<E>(&vk.delta_g2, 1),
+                ),
+            ],
+            0,
+        );
+        let ic = vk
+            .gamma_abc_g1
+            .iter()
+            .map(|point| g1_point_literal::<E>(point, 1))
+            .collect::<Vec<_>>();
+        let precomputed_lines = precomputed_lines
+            .iter()
+            .map(|line| g2_line_literal(line, 1))
+            .collect::<Vec<_>>();
+
+        Ok([
+            format!("use garaga::definitions::{{E12D, G1Point, G2Line, G2Point, {integer_types}}};\nuse garaga::groth16::Groth16VerifyingKey;\n"),
+            format!(
+                "pub const N_PUBLIC_INPUTS: usize = {};\n",
+                vk.gamma_abc_g1.len() - 1
+            ),
+            format!("pub const vk: Groth16VerifyingKey = {verifying_key};\n"),
+            array_constant("ic", "G1Point", &ic),
+            array_constant("precomputed_lines", "G2Line", &precomputed_lines),
+        ]
+        .join("\n"))
+    }
+}
@@ -0,0 +1,70 @@
+mod encoding {
+    use num_bigint::BigUint;
+
+    use crate::cairo::{limbs, uint_literal};
+
+    #[test]
+    fn limbs_of_96_bits() {
+        let value = (BigUint::from(3u8) << 192) + (BigUint::from(2u8) << 96) + 1u8;
+        assert_eq!(limbs::<4>(&value), [1u8, 2, 3, 0].map(BigUint::from));
+    }
+
+    #[test]
+    fn literals() {
+        let value = ark_bn254::Fq::from(0xabu8) + ark_bn254::Fq::from(2u128.pow(100));
+        assert_eq!(
+            uint_literal::<_, 4>(&value),
+            "u384 { limb0: 0xab, limb1: 0x10, limb2: 0x0, limb3: 0x0 }"
+        );
+        assert_eq!(
+            uint_literal::<_, 3>(&value),
+            "u288 { limb0: 0xab, limb1: 0x10, limb2: 0x0 }"
+        );
+    }
+}
+
+mod groth_16_verifier_configuration {
+    use ark_ec::AffineRepr;
+    use assert_matches::assert_matches;
+
+    use crate::{
+        test_utils::random_verifying_key, GaragaCurve, GaragaError, Groth16VerifierConfiguration,
+    };
+
+    fn cairo_constants_for<E: GaragaCurve>(integer_types: &str, hint_type: &str) {
+        let mut vk = random_verifying_key::<E>(3);
+        vk.gamma_abc_g1[1] = E::G1Affine::zero();
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let n_lines = config.precomputed_lines().unwrap().interleaved().len();
+
+        let code = config.cairo_constants().unwrap();
+        assert!(code.starts_with(&format!(
+            "use garaga::definitions::{{E12D, G1Point, G2Line, G2Point, {integer_types}}};\nuse garaga::groth16::Groth16VerifyingKey;\n\npub const N_PUBLIC_INPUTS: usize = 2;\n\npub const vk: Groth16VerifyingKey = Groth16VerifyingKey {{\n    alpha_beta_miller_loop_result: E12D {{\n        w0: {hint_type} {{ limb0: 0x"
+        )));
+        assert!(code.contains("\n    gamma_g2: G2Point {\n        x0: u384 { limb0: 0x"));
+        assert!(code.contains(
+            "\npub const ic: [G1Point; 3] = [\n    G1Point {\n        x: u384 { limb0: 0x"
+        ));
+        assert!(code.contains("    G1Point {\n        x: u384 { limb0: 0x0, limb1: 0x0, limb2: 0x0, limb3: 0x0 },\n        y: u384 { limb0: 0x0, limb1: 0x0, limb2: 0x0, limb3: 0x0 },\n    },\n"));
+        assert!(code.contains(&format!(
+            "\npub const precomputed_lines: [G2Line; {n_lines}] = [\n    G2Line {{\n        r0a0: {hint_type} {{ limb0: 0x"
+        )));
+        assert_eq!(code.matches("G2Line {").count(), n_lines);
+        assert!(code.ends_with("    },\n];\n"));
+    }
+
+    #[test]
+    fn cairo_constants() {
+        cairo_constants_for::<ark_bn254::Bn254>("u288, u384", "u288");
+        cairo_constants_for::<ark_bls12_381::Bls12_381>("u384", "u384");
+    }
+
+    #[test]
+    fn cairo_constants_rejects_key_without_ic() {
+        let config = Groth16VerifierConfiguration::from_verifying_key(random_verifying_key::<
+            ark_bn254::Bn254,
+        >(0));
+
+        assert_matches!(config.cairo_constants(), Err(GaragaError::NoIc));
+    }
+}
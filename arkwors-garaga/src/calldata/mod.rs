@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests;
+
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::{Field, PrimeField};
+use num_bigint::BigUint;
+pub use starknet_types_core::felt::Felt;
+
+use crate::{
+    check_not_at_infinity, check_public_input_count, GaragaCurve, GaragaError,
+    Groth16VerifierConfiguration, Groth16VerifierInputs, PointId,
+};
+
+/// Little endian limbs of `value`, as expected by the Cairo `Serde` of `u384` (N = 4) and `u288` (N = 3)
+fn limbs<const N: usize>(value: &BigUint) -> [Felt; N] {
+    crate::cairo::limbs(value).map(Felt::from)
+}
+
+/// A prime field element as a Cairo `u384`
+pub(crate) fn u384<F: PrimeField>(value: &F) -> [Felt; 4] {
+    limbs(&(*value).into())
+}
+
+/// A prime field element as a Cairo `u256`, that is its low and high 128 bits
+pub(crate) fn u256<F: PrimeField>(value: &F) -> [Felt; 2] {
+    let value: BigUint = (*value).into();
+    let mask = (BigUint::from(1u8) << 128) - 1u8;
+
+    [Felt::from(&value & &mask), Felt::from(value >> 128)]
+}
+
+/// The base prime field coordinates of `value` as `u384`s, failing if `value` is not of degree `N`
+fn extension_field_u384<F: Field, const N: usize>(
+    value: &F,
+) -> Result<[[Felt; 4]; N], GaragaError> {
+    let coordinates = value
+        .to_base_prime_field_elements()
+        .map(|v| u384(&v))
+        .collect::<Vec<_>>();
+
+    coordinates
+        .try_into()
+        .map_err(|coordinates: Vec<_>| GaragaError::WrongExtensionDegree {
+            expected: N,
+            found: coordinates.len(),
+        })
+}
+
+/// Serialize an affine point as Garaga's `G1Point { x: u384, y: u384 }` (N = 1)
+/// or `G2Point { x0: u384, x1: u384, y0: u384, y1: u384 }` (N = 2)
+///
+/// The point at infinity is written with all its coordinates set to zero, as Garaga encodes it.
+fn serialize_point<A: AffineRepr, const N: usize>(
+    point: &A,
+    calldata: &mut Vec<Felt>,
+) -> Result<(), GaragaError> {
+    let (x, y) = match point.xy() {
+        Some((x, y)) => (
+            extension_field_u384::<_, N>(x)?,
+            extension_field_u384::<_, N>(y)?,
+        ),
+        None => ([[Felt::ZERO; 4]; N], [[Felt::ZERO; 4]; N]),
+    };
+    calldata.extend(x.iter().chain(y.iter()).flatten());
+
+    Ok(())
+}
+
+/// Serialize the `Groth16Proof` struct of Garaga's verifier
+///
+/// ```cairo
+/// struct Groth16Proof {
+///     a: G1Point,
+///     b: G2Point,
+///     c: G1Point,
+///     public_inputs: Span<u256>,
+/// }
+/// ```
+///
+/// Fails if one of the points is the point at infinity.
+fn serialize_groth16_proof<E: Pairing>(
+    proof: &ark_groth16::Proof<E>,
+    public_inputs: &[E::ScalarField],
+    calldata: &mut Vec<Felt>,
+) -> Result<(), GaragaError> {
+    check_not_at_infinity(&proof.a, PointId::A)?;
+    check_not_at_infinity(&proof.b, PointId::B)?;
+    check_not_at_infinity(&proof.c, PointId::C)?;
+    serialize_point::<_, 1>(&proof.a, calldata)?;
+    serialize_point::<_, 2>(&proof.b, calldata)?;
+    serialize_point::<_, 1>(&proof.c, calldata)?;
+
+    calldata.push(Felt::from(public_inputs.len() as u128));
+    calldata.extend(public_inputs.iter().flat_map(u256));
+
+    Ok(())
+}
+
+impl<E: GaragaCurve> Groth16VerifierInputs<E> {
+    /// The serialized `Groth16Proof` the `full_proof_with_hints: Span<felt252>` argument of
+    /// Garaga's `verify_groth16_proof_bn254` and `verify_groth16_proof_bls12_381` entrypoints
+    /// starts with
+    ///
+    /// It is not the whole argument: the MPCheck and MSM hints Garaga's verifiers expect
+    /// after it are drawn from Garaga's Poseidon transcript, which this crate does not
+    /// reproduce, so they are missing, and so is the length prefix of the span.
+    pub fn proof_calldata(
+        &self,
+        config: &Groth16VerifierConfiguration<E>,
+    ) -> Result<Vec<Felt>, GaragaError> {
+        check_public_input_count(config.verifying_key(), self.public_inputs().len())?;
+
+        let mut calldata = Vec::new();
+        serialize_groth16_proof(self.proof(), self.public_inputs(), &mut calldata)?;
+
+        Ok(calldata)
+    }
+}
@@ -0,0 +1,123 @@
+mod encoding {
+    use ark_ff::{BigInteger, PrimeField};
+
+    use crate::calldata::{u256, u384, Felt};
+
+    #[test]
+    fn u384_limbs() {
+        // 2^96 + 5
+        let value = ark_bn254::Fq::from(5u8)
+            + ark_bn254::Fq::from(1u128 << 64) * ark_bn254::Fq::from(1u128 << 32);
+        assert_eq!(
+            u384(&value),
+            [Felt::from(5u8), Felt::ONE, Felt::ZERO, Felt::ZERO]
+        );
+
+        let minus_one = -ark_bls12_381::Fq::from(1u8);
+        let limbs = u384(&minus_one);
+        let mut bytes = minus_one.into_bigint().to_bytes_le();
+        bytes.resize(48, 0);
+        for (limb, chunk) in limbs.iter().zip(bytes.chunks(12)) {
+            let mut expected = [0u8; 32];
+            expected[..12].copy_from_slice(chunk);
+            assert_eq!(limb, &Felt::from_bytes_le(&expected));
+        }
+    }
+
+    #[test]
+    fn u256_halves() {
+        let value =
+            ark_bn254::Fr::from(7u8) + ark_bn254::Fr::from(1u128 << 127) * ark_bn254::Fr::from(2u8);
+        assert_eq!(u256(&value), [Felt::from(7u8), Felt::ONE]);
+    }
+}
+
+mod groth_16_verifier_inputs {
+    use ark_ec::{pairing::Pairing, AffineRepr};
+    use ark_groth16::{Proof, VerifyingKey};
+    use assert_matches::assert_matches;
+
+    use crate::{
+        calldata::{u384, Felt},
+        GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+
+    #[test]
+    fn proof_calldata() {
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<ark_bn254::Bn254> {
+                gamma_abc_g1: vec![ark_bn254::G1Affine::generator(); 3],
+                ..Default::default()
+            });
+        let proof = Proof::<ark_bn254::Bn254> {
+            a: ark_bn254::G1Affine::generator(),
+            b: ark_bn254::G2Affine::generator(),
+            c: ark_bn254::G1Affine::generator(),
+        };
+        let public_inputs = vec![
+            <ark_bn254::Bn254 as Pairing>::ScalarField::from(1u8),
+            <ark_bn254::Bn254 as Pairing>::ScalarField::from(2u8),
+        ];
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+
+        let calldata = inputs.proof_calldata(&config).unwrap();
+
+        let mut expected = vec![];
+        // a is the generator (1, 2)
+        expected.extend([Felt::ONE, Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        expected.extend([Felt::TWO, Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        // b is the generator of G2
+        let b = ark_bn254::G2Affine::generator();
+        let (x, y) = b.xy().unwrap();
+        for coordinate in [x.c0, x.c1, y.c0, y.c1] {
+            expected.extend(u384(&coordinate));
+        }
+        // c is the generator (1, 2)
+        expected.extend([Felt::ONE, Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        expected.extend([Felt::TWO, Felt::ZERO, Felt::ZERO, Felt::ZERO]);
+        // public inputs
+        expected.extend([Felt::TWO, Felt::ONE, Felt::ZERO, Felt::TWO, Felt::ZERO]);
+        assert_eq!(calldata, expected);
+    }
+
+    #[test]
+    fn proof_calldata_rejects_public_input_count_mismatch() {
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<ark_bn254::Bn254> {
+                gamma_abc_g1: vec![ark_bn254::G1Affine::generator(); 2],
+                ..Default::default()
+            });
+        let inputs = Groth16VerifierInputs::from_proof(Proof::default(), vec![]);
+
+        assert_matches!(
+            inputs.proof_calldata(&config),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 1,
+                found: 0
+            })
+        );
+    }
+
+    #[test]
+    fn proof_calldata_rejects_points_at_infinity() {
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<ark_bn254::Bn254> {
+                gamma_abc_g1: vec![ark_bn254::G1Affine::generator()],
+                ..Default::default()
+            });
+        let proof = Proof::<ark_bn254::Bn254> {
+            a: ark_bn254::G1Affine::generator(),
+            b: ark_bn254::G2Affine::generator(),
+            ..Default::default()
+        };
+        let inputs = Groth16VerifierInputs::from_proof(proof, vec![]);
+
+        assert_matches!(
+            inputs.proof_calldata(&config),
+            Err(GaragaError::InvalidPoint {
+                point: crate::PointId::C,
+                reason: crate::InvalidPointReason::AtInfinity
+            })
+        );
+    }
+}
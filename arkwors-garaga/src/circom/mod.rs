@@ -0,0 +1,667 @@
+//! The Groth16 proving keys, constraint systems and witnesses of Circom circuits
+//!
+//! snarkjs writes proving keys to `.zkey` files, Circom writes constraint systems to `.r1cs` files
+//! and its witness generators write witnesses to `.wtns` files. They share the same layout:
+//! a four bytes magic, a version, and a list of sections, each made of its id and its size,
+//! whose integers are little endian. Field elements are little endian too,
+//! the ones of the points being in Montgomery form.
+//!
+//! `R1csFileCircuit` synthesizes the constraint system of a `.r1cs` file,
+//! so that Arkworks can set it up and prove it.
+//!
+//! snarkjs computes the `h_query` of a key over a domain twice as large as arkworks does,
+//! proofs of its keys must be built with the `CircomReduction` witness map, as in
+//! `Groth16::<E, CircomReduction>::prove`.
+
+#[cfg(test)]
+mod tests;
+
+use std::{
+    collections::HashMap,
+    io::{Read, Seek, SeekFrom},
+};
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_groth16::{
+    r1cs_to_qap::{evaluate_constraint, LibsnarkReduction, R1CSToQAP},
+    ProvingKey, VerifyingKey,
+};
+use ark_poly::EvaluationDomain;
+use ark_relations::r1cs::{
+    ConstraintMatrices, ConstraintSynthesizer, ConstraintSystemRef, LinearCombination,
+    SynthesisError, Variable,
+};
+use num_bigint::BigUint;
+
+use crate::{
+    check_point, ElipticCurveId, GaragaCurve, GaragaError, Groth16VerifierConfiguration,
+    InvalidPointReason, PointId,
+};
+
+/// The sections of a file, by id, as their offset and size
+struct Sections<R> {
+    reader: R,
+    sections: HashMap<u32, (u64, u64)>,
+}
+
+impl<R: Read + Seek> Sections<R> {
+    /// Read the header of a file, which must start with `magic`
+    fn new(mut reader: R, magic: &[u8; 4]) -> Result<Self, GaragaError> {
+        let len = reader.seek(SeekFrom::End(0))?;
+        reader.rewind()?;
+        let mut bytes = [0; 4];
+        reader.read_exact(&mut bytes)?;
+        if &bytes != magic {
+            return Err(GaragaError::InvalidCircomFile(format!(
+                "expected a `{}` file",
+                String::from_utf8_lossy(magic)
+            )));
+        }
+
+        let _version = read_u32(&mut reader)?;
+        let mut sections = HashMap::new();
+        for _ in 0..read_u32(&mut reader)? {
+            let id = read_u32(&mut reader)?;
+            let size = read_u64(&mut reader)?;
+            if size > len - reader.stream_position()? {
+                return Err(GaragaError::InvalidCircomFile(format!(
+                    "section {id} goes past the end of the file"
+                )));
+            }
+            // Only the first section of an id is read, as snarkjs does
+            sections
+                .entry(id)
+                .or_insert((reader.stream_position()?, size));
+            reader.seek(SeekFrom::Current(size as i64))?;
+        }
+
+        Ok(Self { reader, sections })
+    }
+
+    fn contains(&self, id: u32) -> bool {
+        self.sections.contains_key(&id)
+    }
+
+    /// Move to the start of the section `id`, returning its size
+    fn seek(&mut self, id: u32) -> Result<u64, GaragaError> {
+        let (offset, size) = *self
+            .sections
+            .get(&id)
+            .ok_or_else(|| GaragaError::InvalidCircomFile(format!("missing section {id}")))?;
+        self.reader.seek(SeekFrom::Start(offset))?;
+
+        Ok(size)
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32, GaragaError> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64, GaragaError> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Read a `u32` size prefixed little endian integer, from a section of `section_size` bytes
+fn read_modulus(
+    reader: &mut impl Read,
+    section_size: u64,
+) -> Result<(usize, BigUint), GaragaError> {
+    let size = read_u32(reader)?;
+    if u64::from(size) > section_size {
+        return Err(GaragaError::InvalidCircomFile(format!(
+            "modulus of {size} bytes in a section of {section_size} bytes"
+        )));
+    }
+    let size = size as usize;
+    let mut bytes = vec![0; size];
+    reader.read_exact(&mut bytes)?;
+
+    Ok((size, BigUint::from_bytes_le(&bytes)))
+}
+
+/// The number of bytes circom writes the elements of `F` with, a multiple of 8
+fn field_size<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize - 1) / 64 * 8 + 8
+}
+
+/// Read a little endian prime field element of `size` bytes, which must be `field_size::<F>()`,
+/// values out of range being returned as `Err` in `Ok`
+fn read_field<F: PrimeField>(
+    reader: &mut impl Read,
+    size: usize,
+) -> Result<Result<F, String>, GaragaError> {
+    if size != field_size::<F>() {
+        return Err(GaragaError::InvalidCircomFile(format!(
+            "field elements of {size} bytes, expected {}",
+            field_size::<F>()
+        )));
+    }
+    let mut bytes = vec![0; size];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(field_from_bytes(&bytes))
+}
+
+/// Parse a little endian prime field element, rejecting values out of range
+fn field_from_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F, String> {
+    let value = BigUint::from_bytes_le(bytes);
+
+    F::BigInt::try_from(value.clone())
+        .ok()
+        .and_then(F::from_bigint)
+        .ok_or_else(|| format!("{value:#x}"))
+}
+
+/// The inverse of the Montgomery factor `2^(8 * size)` snarkjs multiplies elements by
+fn montgomery_inverse<F: PrimeField>(size: usize) -> F {
+    F::from(2u8)
+        .pow([8 * size as u64])
+        .inverse()
+        .expect("powers of two are invertible in odd fields")
+}
+
+/// Read a coordinate, whose base prime field elements are in Montgomery form
+///
+/// Elements out of range are reported with `invalid`.
+fn read_coordinate<F: Field>(
+    reader: &mut impl Read,
+    size: usize,
+    invalid: &impl Fn(String) -> GaragaError,
+) -> Result<F, GaragaError> {
+    let r_inv = montgomery_inverse::<F::BasePrimeField>(size);
+    let elems = (0..F::extension_degree())
+        .map(|_| read_field::<F::BasePrimeField>(reader, size))
+        .collect::<Result<Result<Vec<_>, _>, _>>()?
+        .map_err(invalid)?
+        .into_iter()
+        .map(|value| value * r_inv)
+        .collect::<Vec<_>>();
+
+    Ok(F::from_base_prime_field_elems(&elems).expect("as many elements as the degree"))
+}
+
+/// Read a point, `(0, 0)` being the point at infinity, without checking it
+fn read_point_unchecked<P: SWCurveConfig>(
+    reader: &mut impl Read,
+    size: usize,
+    invalid: &impl Fn(String) -> GaragaError,
+) -> Result<Affine<P>, GaragaError> {
+    let x = read_coordinate::<P::BaseField>(reader, size, invalid)?;
+    let y = read_coordinate::<P::BaseField>(reader, size, invalid)?;
+
+    Ok(if x.is_zero() && y.is_zero() {
+        Affine::zero()
+    } else {
+        Affine::new_unchecked(x, y)
+    })
+}
+
+/// Read a point, making sure it lies in the prime order subgroup
+fn read_point<P: SWCurveConfig>(
+    reader: &mut impl Read,
+    size: usize,
+    point: PointId,
+) -> Result<Affine<P>, GaragaError> {
+    let value = read_point_unchecked(reader, size, &|value| GaragaError::InvalidPoint {
+        point,
+        reason: InvalidPointReason::InvalidCoordinate(value),
+    })?;
+    check_point(&value, point)?;
+
+    Ok(value)
+}
+
+/// The id of the curve whose base field has the modulus `q`
+fn eliptic_curve_id(q: &BigUint) -> Option<ElipticCurveId> {
+    if *q == ark_bn254::Fq::MODULUS.into() {
+        Some(ElipticCurveId::Bn254)
+    } else if *q == ark_bls12_381::Fq::MODULUS.into() {
+        Some(ElipticCurveId::Bls12_381)
+    } else {
+        None
+    }
+}
+
+/// The Groth16 header of a `.zkey` file
+struct Header<E: GaragaCurve> {
+    n8q: usize,
+    n_vars: usize,
+    n_public: usize,
+    domain_size: usize,
+    alpha_g1: E::G1Affine,
+    beta_g1: E::G1Affine,
+    beta_g2: E::G2Affine,
+    gamma_g2: E::G2Affine,
+    delta_g1: E::G1Affine,
+    delta_g2: E::G2Affine,
+}
+
+/// Read the sections of a `.zkey` file and its Groth16 header
+fn read_header<E: GaragaCurve, R: Read + Seek>(
+    reader: R,
+) -> Result<(Sections<R>, Header<E>), GaragaError> {
+    let mut file = Sections::new(reader, b"zkey")?;
+
+    file.seek(1)?;
+    match read_u32(&mut file.reader)? {
+        1 => {}
+        2 => return Err(GaragaError::UnsupportedProtocol("plonk".to_string())),
+        10 => return Err(GaragaError::UnsupportedProtocol("fflonk".to_string())),
+        protocol => {
+            return Err(GaragaError::InvalidCircomFile(format!(
+                "unknown protocol {protocol}"
+            )))
+        }
+    }
+
+    let section_size = file.seek(2)?;
+    let reader = &mut file.reader;
+    let (n8q, q) = read_modulus(reader, section_size)?;
+    match eliptic_curve_id(&q) {
+        Some(found) if found != E::ELIPTIC_CURVE_ID => {
+            return Err(GaragaError::CurveMismatch {
+                expected: E::ELIPTIC_CURVE_ID,
+                found,
+            })
+        }
+        Some(_) => {}
+        None => return Err(GaragaError::InvalidElipticCurveId(format!("{q:#x}"))),
+    }
+    let (_, r) = read_modulus(reader, section_size)?;
+    if r != E::ScalarField::MODULUS.into() {
+        return Err(GaragaError::InvalidCircomFile(format!(
+            "unexpected scalar field modulus {r:#x}"
+        )));
+    }
+    let n_vars = read_u32(reader)? as usize;
+    let n_public = read_u32(reader)? as usize;
+    let domain_size = read_u32(reader)? as usize;
+
+    let header = Header {
+        n8q,
+        n_vars,
+        n_public,
+        domain_size,
+        alpha_g1: read_point(reader, n8q, PointId::AlphaG1)?,
+        beta_g1: read_point(reader, n8q, PointId::BetaG1)?,
+        beta_g2: read_point(reader, n8q, PointId::BetaG2)?,
+        gamma_g2: read_point(reader, n8q, PointId::GammaG2)?,
+        delta_g1: read_point(reader, n8q, PointId::DeltaG1)?,
+        delta_g2: read_point(reader, n8q, PointId::DeltaG2)?,
+    };
+    if n_public >= n_vars {
+        return Err(GaragaError::InvalidCircomFile(format!(
+            "{n_public} public inputs out of {n_vars} variables"
+        )));
+    }
+
+    Ok((file, header))
+}
+
+/// Read the `ic` points of a `.zkey` file, from its section 3
+fn read_ic<E: GaragaCurve, R: Read + Seek>(
+    file: &mut Sections<R>,
+    header: &Header<E>,
+) -> Result<Vec<E::G1Affine>, GaragaError> {
+    file.seek(3)?;
+    (0..=header.n_public)
+        .map(|i| read_point(&mut file.reader, header.n8q, PointId::Ic(i)))
+        .collect()
+}
+
+/// Read the `count` points of the section `id` of a `.zkey` file
+fn read_query<P: SWCurveConfig, R: Read + Seek>(
+    file: &mut Sections<R>,
+    id: u32,
+    count: usize,
+    n8q: usize,
+) -> Result<Vec<Affine<P>>, GaragaError> {
+    file.seek(id)?;
+    (0..count)
+        .map(|_| {
+            read_point_unchecked(&mut file.reader, n8q, &|value| {
+                GaragaError::InvalidCircomFile(format!(
+                    "`{value}` is not a coordinate, in section {id}"
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Read the verifying key of a Groth16 `.zkey` file
+///
+/// Fails if the key is not a Groth16 one over the curve `E`, or if one of its points is invalid.
+pub fn read_verifying_key<E: GaragaCurve>(
+    reader: impl Read + Seek,
+) -> Result<VerifyingKey<E>, GaragaError> {
+    let (mut file, header) = read_header::<E, _>(reader)?;
+
+    Ok(VerifyingKey {
+        alpha_g1: header.alpha_g1,
+        beta_g2: header.beta_g2,
+        gamma_g2: header.gamma_g2,
+        delta_g2: header.delta_g2,
+        gamma_abc_g1: read_ic(&mut file, &header)?,
+    })
+}
+
+/// Read the proving key of a Groth16 `.zkey` file, to be used with `CircomReduction`
+///
+/// Only the points of the verifying key, `beta_g1` and `delta_g1` are checked,
+/// the queries are read as they are.
+pub fn read_proving_key<E: GaragaCurve>(
+    reader: impl Read + Seek,
+) -> Result<ProvingKey<E>, GaragaError> {
+    let (mut file, header) = read_header::<E, _>(reader)?;
+    let (n8q, n_vars) = (header.n8q, header.n_vars);
+
+    let vk = VerifyingKey {
+        alpha_g1: header.alpha_g1,
+        beta_g2: header.beta_g2,
+        gamma_g2: header.gamma_g2,
+        delta_g2: header.delta_g2,
+        gamma_abc_g1: read_ic(&mut file, &header)?,
+    };
+
+    Ok(ProvingKey {
+        vk,
+        beta_g1: header.beta_g1,
+        delta_g1: header.delta_g1,
+        a_query: read_query(&mut file, 5, n_vars, n8q)?,
+        b_g1_query: read_query(&mut file, 6, n_vars, n8q)?,
+        b_g2_query: read_query(&mut file, 7, n_vars, n8q)?,
+        l_query: read_query(&mut file, 8, n_vars - header.n_public - 1, n8q)?,
+        h_query: read_query(&mut file, 9, header.domain_size, n8q)?,
+    })
+}
+
+/// Read a `.wtns` witness, starting with the constant `1`, then the public inputs
+///
+/// Fails if the witness is not over the field `F`.
+pub fn read_witness<F: PrimeField>(reader: impl Read + Seek) -> Result<Vec<F>, GaragaError> {
+    let mut file = Sections::new(reader, b"wtns")?;
+
+    let section_size = file.seek(1)?;
+    let (n8, prime) = read_modulus(&mut file.reader, section_size)?;
+    if prime != F::MODULUS.into() {
+        return Err(GaragaError::InvalidCircomFile(format!(
+            "unexpected field modulus {prime:#x}"
+        )));
+    }
+    let len = read_u32(&mut file.reader)? as usize;
+
+    file.seek(2)?;
+    (0..len)
+        .map(|_| read_field(&mut file.reader, n8)?.map_err(GaragaError::InvalidFieldElement))
+        .collect()
+}
+
+/// A linear combination, as the wires it sums with their coefficients
+type Terms<F> = Vec<(usize, F)>;
+
+/// The circuit of a `.r1cs` file, with an optional witness
+///
+/// Circom numbers wires from the constant `1`, followed by the public outputs, the public inputs,
+/// then the private inputs and the intermediate signals. The public inputs of the Groth16 proofs of
+/// the circuit are its public outputs followed by its public inputs.
+///
+/// Without a witness, the circuit can only be set up.
+#[derive(Clone, Debug)]
+pub struct R1csFileCircuit<F: PrimeField> {
+    num_wires: usize,
+    num_public_inputs: usize,
+    constraints: Vec<[Terms<F>; 3]>,
+    wire_labels: Vec<u64>,
+    witness: Option<Vec<F>>,
+}
+
+impl<F: PrimeField> R1csFileCircuit<F> {
+    /// Read the constraint system of a `.r1cs` file
+    ///
+    /// Fails if the constraint system is not over the field `F`.
+    pub fn read(reader: impl Read + Seek) -> Result<Self, GaragaError> {
+        let mut file = Sections::new(reader, b"r1cs")?;
+
+        let section_size = file.seek(1)?;
+        let reader = &mut file.reader;
+        let (n8, prime) = read_modulus(reader, section_size)?;
+        if prime != F::MODULUS.into() {
+            return Err(GaragaError::InvalidCircomFile(format!(
+                "unexpected field modulus {prime:#x}"
+            )));
+        }
+        let num_wires = read_u32(reader)? as usize;
+        let num_public_outputs = read_u32(reader)? as usize;
+        let num_public_inputs = num_public_outputs + read_u32(reader)? as usize;
+        let _num_private_inputs = read_u32(reader)?;
+        let _num_labels = read_u64(reader)?;
+        let num_constraints = read_u32(reader)? as usize;
+        if num_public_inputs >= num_wires {
+            return Err(GaragaError::InvalidCircomFile(format!(
+                "{num_public_inputs} public inputs out of {num_wires} wires"
+            )));
+        }
+
+        file.seek(2)?;
+        let reader = &mut file.reader;
+        let mut read_terms = || -> Result<Terms<F>, GaragaError> {
+            (0..read_u32(reader)?)
+                .map(|_| {
+                    let wire = read_u32(reader)? as usize;
+                    if wire >= num_wires {
+                        return Err(GaragaError::InvalidCircomFile(format!(
+                            "wire {wire} out of {num_wires}"
+                        )));
+                    }
+                    let coefficient =
+                        read_field(reader, n8)?.map_err(GaragaError::InvalidFieldElement)?;
+
+                    Ok((wire, coefficient))
+                })
+                .collect()
+        };
+        let constraints = (0..num_constraints)
+            .map(|_| Ok([read_terms()?, read_terms()?, read_terms()?]))
+            .collect::<Result<Vec<_>, GaragaError>>()?;
+
+        // Frontends other than Circom may not label wires
+        let wire_labels = if file.contains(3) {
+            if file.seek(3)? != 8 * num_wires as u64 {
+                return Err(GaragaError::InvalidCircomFile(
+                    "the wire labels don't match the wires".to_string(),
+                ));
+            }
+            (0..num_wires)
+                .map(|_| read_u64(&mut file.reader))
+                .collect::<Result<_, _>>()?
+        } else {
+            (0..num_wires as u64).collect()
+        };
+
+        Ok(Self {
+            num_wires,
+            num_public_inputs,
+            constraints,
+            wire_labels,
+            witness: None,
+        })
+    }
+
+    /// Assign the wires, as a `.wtns` file does, see `read_witness`
+    ///
+    /// Fails if there are not as many values as wires, or if the first one is not `1`.
+    pub fn with_witness(mut self, witness: Vec<F>) -> Result<Self, GaragaError> {
+        if witness.len() != self.num_wires {
+            return Err(GaragaError::WitnessSizeMismatch {
+                expected: self.num_wires,
+                found: witness.len(),
+            });
+        }
+        if !witness[0].is_one() {
+            return Err(GaragaError::InvalidCircomFile(
+                "the first wire of the witness is not `1`".to_string(),
+            ));
+        }
+        self.witness = Some(witness);
+
+        Ok(self)
+    }
+
+    pub fn num_wires(&self) -> usize {
+        self.num_wires
+    }
+
+    pub fn num_public_inputs(&self) -> usize {
+        self.num_public_inputs
+    }
+
+    pub fn num_constraints(&self) -> usize {
+        self.constraints.len()
+    }
+
+    /// The label of each wire, that is the index of its signal before Circom optimized it away
+    /// or merged it with others
+    pub fn wire_labels(&self) -> &[u64] {
+        &self.wire_labels
+    }
+
+    /// The public inputs of the proofs of the witness, if any
+    pub fn public_inputs(&self) -> Option<Vec<F>> {
+        self.witness
+            .as_ref()
+            .map(|witness| witness[1..=self.num_public_inputs].to_vec())
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for R1csFileCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let value = |wire: usize| {
+            self.witness
+                .as_ref()
+                .map(|witness| witness[wire])
+                .ok_or(SynthesisError::AssignmentMissing)
+        };
+
+        // Arkworks allocates the constant `1` itself
+        for wire in 1..=self.num_public_inputs {
+            cs.new_input_variable(|| value(wire))?;
+        }
+        for wire in self.num_public_inputs + 1..self.num_wires {
+            cs.new_witness_variable(|| value(wire))?;
+        }
+
+        let variable = |wire: usize| match wire {
+            0 => Variable::One,
+            wire if wire <= self.num_public_inputs => Variable::Instance(wire),
+            wire => Variable::Witness(wire - self.num_public_inputs - 1),
+        };
+        let lc = |terms: &Terms<F>| {
+            terms
+                .iter()
+                .fold(LinearCombination::zero(), |lc, (wire, coefficient)| {
+                    lc + (*coefficient, variable(*wire))
+                })
+        };
+        for [a, b, c] in &self.constraints {
+            cs.enforce_constraint(lc(a), lc(b), lc(c))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Same as `from_verifying_key`, for the key of a `.zkey` file, see `read_verifying_key`
+    pub fn from_zkey(zkey: impl Read + Seek) -> Result<Self, GaragaError> {
+        Ok(Self::from_verifying_key(read_verifying_key(zkey)?))
+    }
+}
+
+/// The witness map of snarkjs, which proofs of its keys need
+///
+/// Arkworks computes the coefficients of `h` from `(a·b - c) / z` over the evaluation domain.
+/// snarkjs precomputes its `h_query` in the Lagrange basis of a domain twice as large, whose odd
+/// elements are a coset of the evaluation domain, over which it evaluates `a·b - c`.
+/// Like snarkjs, the public inputs are constrained by extra rows of `a`.
+pub struct CircomReduction;
+
+impl R1CSToQAP for CircomReduction {
+    #[allow(clippy::type_complexity)]
+    fn instance_map_with_evaluation<F: PrimeField, D: EvaluationDomain<F>>(
+        cs: ConstraintSystemRef<F>,
+        t: &F,
+    ) -> Result<(Vec<F>, Vec<F>, Vec<F>, F, usize, usize), SynthesisError> {
+        LibsnarkReduction::instance_map_with_evaluation::<F, D>(cs, t)
+    }
+
+    fn witness_map_from_matrices<F: PrimeField, D: EvaluationDomain<F>>(
+        matrices: &ConstraintMatrices<F>,
+        num_inputs: usize,
+        num_constraints: usize,
+        full_assignment: &[F],
+    ) -> Result<Vec<F>, SynthesisError> {
+        let domain =
+            D::new(num_constraints + num_inputs).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        let domain_size = domain.size();
+        // The generator of the coset made of the odd elements of the domain twice as large
+        let coset = D::new(2 * domain_size)
+            .ok_or(SynthesisError::PolynomialDegreeTooLarge)?
+            .element(1);
+
+        let mut a = vec![F::zero(); domain_size];
+        let mut b = vec![F::zero(); domain_size];
+        for (i, (a_i, b_i)) in matrices.a.iter().zip(&matrices.b).enumerate() {
+            a[i] = evaluate_constraint(a_i, full_assignment);
+            b[i] = evaluate_constraint(b_i, full_assignment);
+        }
+        a[num_constraints..num_constraints + num_inputs]
+            .clone_from_slice(&full_assignment[..num_inputs]);
+        let mut c = a[..num_constraints]
+            .iter()
+            .zip(&b)
+            .map(|(a, b)| *a * b)
+            .collect::<Vec<_>>();
+        c.resize(domain_size, F::zero());
+
+        // Evaluations over the domain, to evaluations over the coset
+        for evals in [&mut a, &mut b, &mut c] {
+            domain.ifft_in_place(evals);
+            D::distribute_powers_and_mul_by_const(evals, coset, F::one());
+            domain.fft_in_place(evals);
+        }
+
+        Ok(a.iter()
+            .zip(&b)
+            .zip(&c)
+            .map(|((a, b), c)| *a * b - c)
+            .collect())
+    }
+
+    fn h_query_scalars<F: PrimeField, D: EvaluationDomain<F>>(
+        max_power: usize,
+        t: F,
+        _: F,
+        delta_inverse: F,
+    ) -> Result<Vec<F>, SynthesisError> {
+        // `h·z` has twice as many coefficients as `h`
+        let mut scalars = (0..2 * max_power + 1)
+            .map(|i| delta_inverse * t.pow([i as u64]))
+            .collect::<Vec<_>>();
+        let domain = D::new(scalars.len()).ok_or(SynthesisError::PolynomialDegreeTooLarge)?;
+        // The Lagrange coefficients, of which the odd ones
+        domain.ifft_in_place(&mut scalars);
+
+        Ok(scalars.into_iter().skip(1).step_by(2).collect())
+    }
+}
@@ -0,0 +1,251 @@
+// The multiplier `c = a·b` of `multiplier.circom`, with a key snarkjs set up for it and its
+// verifying key, taken from the test data of ark-circom 0.1.0, and the witness of `3·11 = 33`
+const R1CS: &[u8] = include_bytes!("../../fixtures/circom/multiplier.r1cs");
+const ZKEY: &[u8] = include_bytes!("../../fixtures/circom/multiplier.zkey");
+const WITNESS: &[u8] = include_bytes!("../../fixtures/circom/multiplier.wtns");
+#[cfg(feature = "serde")]
+const VERIFYING_KEY: &str = include_str!("../../fixtures/circom/verification_key.json");
+
+/// The offset of `alpha_g1` in `ZKEY`, after the file and section headers, the moduli,
+/// the number of variables and public inputs and the domain size
+const ALPHA_G1_OFFSET: usize = 124;
+
+mod read_verifying_key {
+    use std::io::Cursor;
+
+    use assert_matches::assert_matches;
+
+    use super::{ALPHA_G1_OFFSET, WITNESS, ZKEY};
+    use crate::{
+        circom::read_verifying_key, ElipticCurveId, GaragaError, InvalidPointReason, PointId,
+    };
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn matches_snarkjs() {
+        use super::VERIFYING_KEY;
+        use crate::{snarkjs::SnarkjsVerifyingKey, Groth16VerifierConfiguration};
+
+        let expected: SnarkjsVerifyingKey = serde_json::from_str(VERIFYING_KEY).unwrap();
+        let config = Groth16VerifierConfiguration::from_zkey(Cursor::new(ZKEY)).unwrap();
+
+        assert_eq!(
+            config.verifying_key(),
+            &expected.to_ark::<ark_bn254::Bn254>().unwrap()
+        );
+    }
+
+    #[test]
+    fn refuses_other_curves_and_files() {
+        assert_matches!(
+            read_verifying_key::<ark_bls12_381::Bls12_381>(Cursor::new(ZKEY)),
+            Err(GaragaError::CurveMismatch {
+                expected: ElipticCurveId::Bls12_381,
+                found: ElipticCurveId::Bn254
+            })
+        );
+        assert_matches!(
+            read_verifying_key::<ark_bn254::Bn254>(Cursor::new(WITNESS)),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "expected a `zkey` file"
+        );
+        assert_matches!(
+            read_verifying_key::<ark_bn254::Bn254>(Cursor::new(&ZKEY[..ALPHA_G1_OFFSET])),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "section 2 goes past the end of the file"
+        );
+    }
+
+    #[test]
+    fn refuses_invalid_points() {
+        let mut zkey = ZKEY.to_vec();
+        zkey[ALPHA_G1_OFFSET] ^= 1;
+        assert_matches!(
+            read_verifying_key::<ark_bn254::Bn254>(Cursor::new(&zkey)),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::AlphaG1,
+                reason: InvalidPointReason::NotOnCurve
+            })
+        );
+
+        let mut zkey = ZKEY.to_vec();
+        zkey[ALPHA_G1_OFFSET..ALPHA_G1_OFFSET + 32].fill(0xff);
+        assert_matches!(
+            read_verifying_key::<ark_bn254::Bn254>(Cursor::new(&zkey)),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::AlphaG1,
+                reason: InvalidPointReason::InvalidCoordinate(_)
+            })
+        );
+    }
+}
+
+mod read_witness {
+    use std::io::Cursor;
+
+    use assert_matches::assert_matches;
+
+    use super::WITNESS;
+    use crate::{circom::read_witness, GaragaError};
+
+    #[test]
+    fn reads_the_assignment() {
+        let witness = read_witness::<ark_bn254::Fr>(Cursor::new(WITNESS)).unwrap();
+
+        assert_eq!(witness, [1u8, 33, 3, 11].map(ark_bn254::Fr::from));
+    }
+
+    #[test]
+    fn refuses_other_fields() {
+        assert_matches!(
+            read_witness::<ark_bls12_381::Fr>(Cursor::new(WITNESS)),
+            Err(GaragaError::InvalidCircomFile(message)) if message.starts_with("unexpected field modulus")
+        );
+
+        // The last value out of range
+        let mut witness = WITNESS.to_vec();
+        let len = witness.len();
+        witness[len - 32..].fill(0xff);
+        assert_matches!(
+            read_witness::<ark_bn254::Fr>(Cursor::new(&witness)),
+            Err(GaragaError::InvalidFieldElement(_))
+        );
+    }
+
+    #[test]
+    fn refuses_sizes_out_of_bounds() {
+        // The size of the header section
+        let mut witness = WITNESS.to_vec();
+        witness[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_matches!(
+            read_witness::<ark_bn254::Fr>(Cursor::new(&witness)),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "section 1 goes past the end of the file"
+        );
+
+        // The size of the modulus
+        let mut witness = WITNESS.to_vec();
+        witness[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_matches!(
+            read_witness::<ark_bn254::Fr>(Cursor::new(&witness)),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "modulus of 4294967295 bytes in a section of 40 bytes"
+        );
+
+        // The modulus padded to 40 bytes, which makes the values 40 bytes long too
+        let mut witness = WITNESS.to_vec();
+        witness[16] = 48;
+        witness[24] = 40;
+        witness.splice(60..60, [0; 8]);
+        assert_matches!(
+            read_witness::<ark_bn254::Fr>(Cursor::new(&witness)),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "field elements of 40 bytes, expected 32"
+        );
+    }
+}
+
+mod r1cs_file_circuit {
+    use std::io::Cursor;
+
+    use ark_crypto_primitives::snark::{CircuitSpecificSetupSNARK, SNARK};
+    use ark_groth16::Groth16;
+    use ark_std::{
+        rand::{rngs::StdRng, RngCore, SeedableRng},
+        test_rng,
+    };
+    use assert_matches::assert_matches;
+
+    use super::{R1CS, WITNESS, ZKEY};
+    use crate::{
+        circom::{read_proving_key, read_witness, CircomReduction, R1csFileCircuit},
+        GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+
+    fn circuit() -> R1csFileCircuit<ark_bn254::Fr> {
+        let witness = read_witness(Cursor::new(WITNESS)).unwrap();
+        R1csFileCircuit::read(Cursor::new(R1CS))
+            .unwrap()
+            .with_witness(witness)
+            .unwrap()
+    }
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(test_rng().next_u64())
+    }
+
+    #[test]
+    fn reads_the_constraint_system() {
+        let circuit = circuit();
+
+        assert_eq!(circuit.num_wires(), 4);
+        assert_eq!(circuit.num_public_inputs(), 1);
+        assert_eq!(circuit.num_constraints(), 1);
+        // The output `c` comes first among the wires, last among the signals
+        assert_eq!(circuit.wire_labels(), [0, 3, 1, 2]);
+        assert_eq!(circuit.public_inputs(), Some(vec![ark_bn254::Fr::from(33)]));
+    }
+
+    #[test]
+    fn proves_with_snarkjs_keys() {
+        let pk = read_proving_key::<ark_bn254::Bn254>(Cursor::new(ZKEY)).unwrap();
+        let circuit = circuit();
+        let public_inputs = circuit.public_inputs().unwrap();
+
+        let proof =
+            Groth16::<ark_bn254::Bn254, CircomReduction>::prove(&pk, circuit, &mut rng()).unwrap();
+
+        let config = Groth16VerifierConfiguration::from_verifying_key(pk.vk.clone());
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+        inputs.verify(&config).unwrap();
+    }
+
+    #[test]
+    fn sets_up_without_witness() {
+        let setup = R1csFileCircuit::read(Cursor::new(R1CS)).unwrap();
+        let (pk, vk) = Groth16::<ark_bn254::Bn254>::setup(setup, &mut rng()).unwrap();
+        let circuit = circuit();
+        let public_inputs = circuit.public_inputs().unwrap();
+
+        let proof = Groth16::<ark_bn254::Bn254>::prove(&pk, circuit, &mut rng()).unwrap();
+
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+        inputs.verify(&config).unwrap();
+    }
+
+    #[test]
+    fn refuses_invalid_witnesses() {
+        let circuit = R1csFileCircuit::<ark_bn254::Fr>::read(Cursor::new(R1CS)).unwrap();
+
+        assert_matches!(
+            circuit
+                .clone()
+                .with_witness(vec![1.into(), 33.into(), 3.into()]),
+            Err(GaragaError::WitnessSizeMismatch {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_matches!(
+            circuit.with_witness(vec![0.into(), 33.into(), 3.into(), 11.into()]),
+            Err(GaragaError::InvalidCircomFile(_))
+        );
+        assert_matches!(
+            R1csFileCircuit::<ark_bls12_381::Fr>::read(Cursor::new(R1CS)),
+            Err(GaragaError::InvalidCircomFile(message)) if message.starts_with("unexpected field modulus")
+        );
+    }
+
+    #[test]
+    fn refuses_sizes_out_of_bounds() {
+        // The size of the header section, then the size of the modulus
+        let mut r1cs = R1CS.to_vec();
+        r1cs[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_matches!(
+            R1csFileCircuit::<ark_bn254::Fr>::read(Cursor::new(&r1cs)),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "section 1 goes past the end of the file"
+        );
+        let mut r1cs = R1CS.to_vec();
+        r1cs[24..28].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_matches!(
+            R1csFileCircuit::<ark_bn254::Fr>::read(Cursor::new(&r1cs)),
+            Err(GaragaError::InvalidCircomFile(message)) if message == "modulus of 4294967295 bytes in a section of 64 bytes"
+        );
+    }
+}
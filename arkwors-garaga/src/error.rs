@@ -0,0 +1,102 @@
+use std::fmt;
+
+use crate::ElipticCurveId;
+
+/// Everything that can go wrong when converting between Arkworks and Garaga representations
+#[derive(Debug, thiserror::Error)]
+pub enum GaragaError {
+    #[error("eliptic curve mismatch: expected {expected:?}, found {found:?}")]
+    CurveMismatch {
+        expected: ElipticCurveId,
+        found: ElipticCurveId,
+    },
+    #[error("`{0}` is not the id of an eliptic curve supported by Garaga")]
+    InvalidElipticCurveId(String),
+    #[error("`{0}` proofs are not supported, only `groth16` ones are")]
+    UnsupportedProtocol(String),
+    #[error("wrong extension degree: expected {expected}, found {found}")]
+    WrongExtensionDegree { expected: usize, found: usize },
+    #[error("wrong number of public inputs: expected {expected}, found {found}")]
+    PublicInputCountMismatch { expected: usize, found: usize },
+    #[error("the verifying key has no `ic` point")]
+    NoIc,
+    #[error("`{0}` is not a valid field element")]
+    InvalidFieldElement(String),
+    #[error("invalid point `{point}`: {reason}")]
+    InvalidPoint {
+        point: PointId,
+        reason: InvalidPointReason,
+    },
+    #[error("gnark's Pedersen commitment extension of Groth16 is not supported")]
+    UnsupportedCommitments,
+    #[error("invalid seal: {0}")]
+    InvalidSeal(String),
+    #[error("invalid Circom file: {0}")]
+    InvalidCircomFile(String),
+    #[error("invalid JSON: {0}")]
+    InvalidJson(String),
+    #[error("wrong number of witness values: expected {expected}, found {found}")]
+    WitnessSizeMismatch { expected: usize, found: usize },
+    #[error("`{0}` is not a valid Scarb package name")]
+    InvalidProjectName(String),
+    #[error("the verifying key failed its audit: {0}")]
+    UnsafeVerifyingKey(crate::AuditReport),
+    #[error("the pairing check does not hold, the proof is invalid")]
+    PairingCheckFailed,
+    #[error(transparent)]
+    Synthesis(#[from] ark_relations::r1cs::SynthesisError),
+    #[error(transparent)]
+    Serialization(#[from] ark_serialize::SerializationError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Identifies a point of a Groth16 verifying key or proof
+///
+/// It displays as the name of the matching field in Garaga's `.json` files.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointId {
+    AlphaG1,
+    BetaG2,
+    GammaG2,
+    DeltaG2,
+    Ic(usize),
+    /// The G1 counterpart of `beta_g2`, which only provers need
+    BetaG1,
+    /// The G1 counterpart of `delta_g2`, which only provers need
+    DeltaG1,
+    A,
+    B,
+    C,
+}
+
+impl fmt::Display for PointId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointId::AlphaG1 => write!(f, "alpha_g1"),
+            PointId::BetaG2 => write!(f, "beta_g2"),
+            PointId::GammaG2 => write!(f, "gamma_g2"),
+            PointId::DeltaG2 => write!(f, "delta_g2"),
+            PointId::Ic(i) => write!(f, "ic[{}]", i),
+            PointId::BetaG1 => write!(f, "beta_g1"),
+            PointId::DeltaG1 => write!(f, "delta_g1"),
+            PointId::A => write!(f, "a"),
+            PointId::B => write!(f, "b"),
+            PointId::C => write!(f, "c"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum InvalidPointReason {
+    #[error("coordinate `{0}` is not a valid field element")]
+    InvalidCoordinate(String),
+    #[error("point is not on the curve")]
+    NotOnCurve,
+    #[error("point is not in the prime order subgroup")]
+    NotInSubgroup,
+    #[error("point is the point at infinity")]
+    AtInfinity,
+    #[error("the flags of the point encoding are invalid")]
+    InvalidEncoding,
+}
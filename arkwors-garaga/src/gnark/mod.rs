@@ -0,0 +1,515 @@
+//! The Groth16 verifying keys, proofs and public witnesses of gnark
+//!
+//! gnark writes them with `WriteTo`, which compresses the points, or `WriteRawTo`, which
+//! doesn't. Both are read here, as gnark itself tells them apart point by point from the
+//! flags in the most significant bits of their first byte. The layouts are the ones of
+//! gnark 0.9 and later, where keys and proofs end with their Pedersen commitment data.
+//!
+//! Circuits using gnark's Pedersen commitment extension need an extra check Garaga's
+//! verifiers don't do, their keys and proofs are refused with `UnsupportedCommitments`.
+//!
+//! With the `serde` feature, `GnarkVerifyingKey` and `GnarkProof` read the `.json` gnark gets
+//! from Go's `encoding/json`.
+
+#[cfg(test)]
+mod tests;
+
+use std::io::Read;
+
+use ark_ec::{
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_groth16::{Proof, VerifyingKey};
+use num_bigint::BigUint;
+
+use crate::{
+    check_point, ElipticCurveId, GaragaCurve, GaragaError, Groth16VerifierConfiguration,
+    Groth16VerifierInputs, InvalidPointReason, PointId,
+};
+
+/// What the most significant bits of an encoded point say about it
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Flag {
+    Uncompressed,
+    UncompressedInfinity,
+    CompressedInfinity,
+    /// Compressed, `y` being the smallest of `y` and `-y`
+    CompressedSmallest,
+    /// Compressed, `y` being the largest of `y` and `-y`
+    CompressedLargest,
+}
+
+impl Flag {
+    /// The mask of the flag bits, two for BN254 and three for BLS12-381
+    fn mask(eliptic_curve_id: ElipticCurveId) -> u8 {
+        match eliptic_curve_id {
+            ElipticCurveId::Bn254 => 0b1100_0000,
+            ElipticCurveId::Bls12_381 => 0b1110_0000,
+        }
+    }
+
+    fn of(eliptic_curve_id: ElipticCurveId, byte: u8) -> Option<Self> {
+        match (eliptic_curve_id, byte & Self::mask(eliptic_curve_id)) {
+            (_, 0b0000_0000) => Some(Self::Uncompressed),
+            (ElipticCurveId::Bn254, 0b0100_0000) => Some(Self::CompressedInfinity),
+            (ElipticCurveId::Bn254, 0b1000_0000) => Some(Self::CompressedSmallest),
+            (ElipticCurveId::Bn254, 0b1100_0000) => Some(Self::CompressedLargest),
+            (ElipticCurveId::Bls12_381, 0b0100_0000) => Some(Self::UncompressedInfinity),
+            (ElipticCurveId::Bls12_381, 0b1000_0000) => Some(Self::CompressedSmallest),
+            (ElipticCurveId::Bls12_381, 0b1010_0000) => Some(Self::CompressedLargest),
+            (ElipticCurveId::Bls12_381, 0b1100_0000) => Some(Self::CompressedInfinity),
+            _ => None,
+        }
+    }
+
+    fn is_compressed(self) -> bool {
+        !matches!(self, Self::Uncompressed | Self::UncompressedInfinity)
+    }
+}
+
+/// The number of bytes of a big endian prime field element
+fn field_bytes<F: PrimeField>() -> usize {
+    F::MODULUS_BIT_SIZE.div_ceil(8) as usize
+}
+
+fn read_u32(reader: &mut impl Read) -> Result<u32, GaragaError> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u32::from_be_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64, GaragaError> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+
+    Ok(u64::from_be_bytes(bytes))
+}
+
+/// Parse a big endian prime field element, rejecting values out of range
+fn field_from_bytes<F: PrimeField>(bytes: &[u8]) -> Result<F, String> {
+    let value = BigUint::from_bytes_be(bytes);
+
+    F::BigInt::try_from(value.clone())
+        .ok()
+        .and_then(F::from_bigint)
+        .ok_or_else(|| format!("{value:#x}"))
+}
+
+/// Parse a coordinate, whose `Fp2` elements gnark writes `A1` first
+fn coordinate_from_bytes<F: Field>(bytes: &[u8], point: PointId) -> Result<F, GaragaError> {
+    let elems = bytes
+        .chunks(field_bytes::<F::BasePrimeField>())
+        .rev()
+        .map(field_from_bytes)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|value| GaragaError::InvalidPoint {
+            point,
+            reason: InvalidPointReason::InvalidCoordinate(value),
+        })?;
+
+    F::from_base_prime_field_elems(&elems).ok_or(GaragaError::WrongExtensionDegree {
+        expected: F::extension_degree() as usize,
+        found: elems.len(),
+    })
+}
+
+/// Read the bytes of a point: its flag, and its `x` coordinate followed by `y` if uncompressed
+fn read_point_bytes<P: SWCurveConfig>(
+    reader: &mut impl Read,
+    eliptic_curve_id: ElipticCurveId,
+    point: PointId,
+) -> Result<(Flag, Vec<u8>), GaragaError> {
+    let coordinate_bytes = P::BaseField::extension_degree() as usize
+        * field_bytes::<<P::BaseField as Field>::BasePrimeField>();
+    let mut bytes = vec![0; coordinate_bytes];
+    reader.read_exact(&mut bytes)?;
+
+    let flag = Flag::of(eliptic_curve_id, bytes[0]).ok_or(GaragaError::InvalidPoint {
+        point,
+        reason: InvalidPointReason::InvalidEncoding,
+    })?;
+    bytes[0] &= !Flag::mask(eliptic_curve_id);
+    if !flag.is_compressed() {
+        bytes.resize(2 * coordinate_bytes, 0);
+        reader.read_exact(&mut bytes[coordinate_bytes..])?;
+    }
+
+    Ok((flag, bytes))
+}
+
+/// Read a point, making sure it lies in the prime order subgroup
+///
+/// Like gnark, an uncompressed `(0, 0)` is read as the point at infinity.
+fn read_point<P: SWCurveConfig>(
+    reader: &mut impl Read,
+    eliptic_curve_id: ElipticCurveId,
+    point: PointId,
+) -> Result<Affine<P>, GaragaError> {
+    let (flag, bytes) = read_point_bytes::<P>(reader, eliptic_curve_id, point)?;
+
+    let value = match flag {
+        Flag::UncompressedInfinity | Flag::CompressedInfinity => Affine::zero(),
+        Flag::Uncompressed => {
+            let (x, y) = bytes.split_at(bytes.len() / 2);
+            let (x, y) = (
+                coordinate_from_bytes::<P::BaseField>(x, point)?,
+                coordinate_from_bytes::<P::BaseField>(y, point)?,
+            );
+            if x.is_zero() && y.is_zero() {
+                Affine::zero()
+            } else {
+                Affine::new_unchecked(x, y)
+            }
+        }
+        Flag::CompressedSmallest | Flag::CompressedLargest => {
+            let x = coordinate_from_bytes(&bytes, point)?;
+            // Arkworks orders `Fp2` elements by `c1` first, like gnark's lexicographic order
+            Affine::get_point_from_x_unchecked(x, flag == Flag::CompressedLargest).ok_or(
+                GaragaError::InvalidPoint {
+                    point,
+                    reason: InvalidPointReason::NotOnCurve,
+                },
+            )?
+        }
+    };
+    check_point(&value, point)?;
+
+    Ok(value)
+}
+
+/// Read a point Groth16 verifiers don't need, only making sure it is well encoded
+fn skip_point<P: SWCurveConfig>(
+    reader: &mut impl Read,
+    eliptic_curve_id: ElipticCurveId,
+    point: PointId,
+) -> Result<(), GaragaError> {
+    read_point_bytes::<P>(reader, eliptic_curve_id, point).map(|_| ())
+}
+
+/// Read the `uint32` length prefixed points gnark writes for a slice of points
+fn read_points<P: SWCurveConfig>(
+    reader: &mut impl Read,
+    eliptic_curve_id: ElipticCurveId,
+    point: impl Fn(usize) -> PointId,
+) -> Result<Vec<Affine<P>>, GaragaError> {
+    (0..read_u32(reader)? as usize)
+        .map(|i| read_point(reader, eliptic_curve_id, point(i)))
+        .collect()
+}
+
+/// Read a verifying key written by gnark's `VerifyingKey.WriteTo` or `WriteRawTo`
+///
+/// gnark calls `ic` `K`. Fails if one of the points is invalid,
+/// or if the key uses the Pedersen commitment extension.
+pub fn read_verifying_key<E: GaragaCurve>(
+    mut reader: impl Read,
+) -> Result<VerifyingKey<E>, GaragaError> {
+    let curve = E::ELIPTIC_CURVE_ID;
+    let reader = &mut reader;
+
+    // [α]1, [β]1, [β]2, [γ]2, [δ]1, [δ]2, the G1 `β` and `δ` only matter to provers
+    let alpha_g1 = read_point(reader, curve, PointId::AlphaG1)?;
+    skip_point::<E::G1Config>(reader, curve, PointId::BetaG1)?;
+    let beta_g2 = read_point(reader, curve, PointId::BetaG2)?;
+    let gamma_g2 = read_point(reader, curve, PointId::GammaG2)?;
+    skip_point::<E::G1Config>(reader, curve, PointId::DeltaG1)?;
+    let delta_g2 = read_point(reader, curve, PointId::DeltaG2)?;
+    let gamma_abc_g1 = read_points(reader, curve, PointId::Ic)?;
+
+    // The public inputs each commitment commits to, then the commitment keys
+    let mut has_commitments = false;
+    for _ in 0..read_u32(reader)? {
+        has_commitments = true;
+        for _ in 0..read_u32(reader)? {
+            read_u64(reader)?;
+        }
+    }
+    if has_commitments || read_u32(reader)? != 0 {
+        return Err(GaragaError::UnsupportedCommitments);
+    }
+
+    Ok(VerifyingKey {
+        alpha_g1,
+        beta_g2,
+        gamma_g2,
+        delta_g2,
+        gamma_abc_g1,
+    })
+}
+
+/// Read a proof written by gnark's `Proof.WriteTo` or `WriteRawTo`
+///
+/// gnark calls `a`, `b` and `c` `Ar`, `Bs` and `Krs`. Fails if one of the points is invalid,
+/// or if the proof holds Pedersen commitments.
+pub fn read_proof<E: GaragaCurve>(mut reader: impl Read) -> Result<Proof<E>, GaragaError> {
+    let curve = E::ELIPTIC_CURVE_ID;
+    let reader = &mut reader;
+
+    let a = read_point(reader, curve, PointId::A)?;
+    let b = read_point(reader, curve, PointId::B)?;
+    let c = read_point(reader, curve, PointId::C)?;
+
+    // The commitments, then their batched proof of knowledge, at infinity without commitments,
+    // whose encoding errors are reported as the ones of `c`
+    if read_u32(reader)? != 0 {
+        return Err(GaragaError::UnsupportedCommitments);
+    }
+    skip_point::<E::G1Config>(reader, curve, PointId::C)?;
+
+    Ok(Proof { a, b, c })
+}
+
+/// Read the public inputs of a public witness written by gnark's `Witness.WriteTo`
+///
+/// It is laid out as `uint32(nbPublic) | uint32(nbSecret) | uint32(len) | elements`,
+/// the elements being big endian. A full witness is accepted too, its secret part being dropped.
+pub fn read_public_witness<F: PrimeField>(mut reader: impl Read) -> Result<Vec<F>, GaragaError> {
+    let reader = &mut reader;
+    let n_public = read_u32(reader)? as usize;
+    let n_secret = read_u32(reader)? as usize;
+    let len = read_u32(reader)? as usize;
+    if len != n_public + n_secret {
+        return Err(GaragaError::PublicInputCountMismatch {
+            expected: n_public + n_secret,
+            found: len,
+        });
+    }
+
+    let mut bytes = vec![0; field_bytes::<F>()];
+    (0..len)
+        .map(|_| {
+            reader.read_exact(&mut bytes)?;
+            field_from_bytes(&bytes).map_err(GaragaError::InvalidFieldElement)
+        })
+        .take(n_public)
+        .collect()
+}
+
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Same as `from_verifying_key`, for a key written by gnark, see `read_verifying_key`
+    pub fn from_gnark(verifying_key: impl Read) -> Result<Self, GaragaError> {
+        Ok(Self::from_verifying_key(read_verifying_key(verifying_key)?))
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierInputs<E> {
+    /// Same as `from_proof`, for a proof and a public witness written by gnark,
+    /// see `read_proof` and `read_public_witness`
+    pub fn from_gnark(proof: impl Read, public_witness: impl Read) -> Result<Self, GaragaError> {
+        Ok(Self::from_proof(
+            read_proof(proof)?,
+            read_public_witness(public_witness)?,
+        ))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod json {
+    use ark_ec::{
+        short_weierstrass::{Affine, SWCurveConfig},
+        AffineRepr,
+    };
+    use ark_ff::{Field, PrimeField, Zero};
+    use ark_groth16::{Proof, VerifyingKey};
+    use num_bigint::BigUint;
+    use serde::{de::IgnoredAny, Deserialize, Deserializer};
+
+    use crate::{check_point, GaragaCurve, GaragaError, InvalidPointReason, PointId};
+
+    /// A base field element, which gnark writes as a decimal number, quoted unless it is small
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct GnarkElement(pub String);
+
+    impl<'de> Deserialize<'de> for GnarkElement {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            #[derive(Deserialize)]
+            #[serde(untagged)]
+            enum Element {
+                Number(u64),
+                String(String),
+            }
+
+            Ok(Self(match Element::deserialize(deserializer)? {
+                Element::Number(value) => value.to_string(),
+                Element::String(value) => value,
+            }))
+        }
+    }
+
+    impl GnarkElement {
+        /// gnark also reads `0x` prefixed hex strings, so do we
+        fn to_prime_field<F: PrimeField>(&self, point: PointId) -> Result<F, GaragaError> {
+            let value = match self.0.strip_prefix("0x") {
+                Some(digits) => BigUint::parse_bytes(digits.as_bytes(), 16),
+                None => BigUint::parse_bytes(self.0.as_bytes(), 10),
+            };
+
+            value
+                .and_then(|value| F::BigInt::try_from(value).ok())
+                .and_then(F::from_bigint)
+                .ok_or_else(|| GaragaError::InvalidPoint {
+                    point,
+                    reason: InvalidPointReason::InvalidCoordinate(self.0.clone()),
+                })
+        }
+    }
+
+    /// An `Fp2` element, `A0 + A1·u`
+    #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkE2 {
+        pub a0: GnarkElement,
+        pub a1: GnarkElement,
+    }
+
+    /// A G1 point, `(0, 0)` being the point at infinity
+    #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkG1Point {
+        pub x: GnarkElement,
+        pub y: GnarkElement,
+    }
+
+    /// A G2 point, `(0, 0)` being the point at infinity
+    #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkG2Point {
+        pub x: GnarkE2,
+        pub y: GnarkE2,
+    }
+
+    /// Parse the base prime field elements of a coordinate
+    fn coordinate<F: Field>(elements: &[&GnarkElement], point: PointId) -> Result<F, GaragaError> {
+        let elems = elements
+            .iter()
+            .map(|element| element.to_prime_field(point))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        F::from_base_prime_field_elems(&elems).ok_or(GaragaError::WrongExtensionDegree {
+            expected: F::extension_degree() as usize,
+            found: elems.len(),
+        })
+    }
+
+    /// Rebuild a point from its coordinates, making sure it lies in the prime order subgroup
+    fn point_from_coordinates<P: SWCurveConfig>(
+        x: &[&GnarkElement],
+        y: &[&GnarkElement],
+        point: PointId,
+    ) -> Result<Affine<P>, GaragaError> {
+        let (x, y) = (
+            coordinate::<P::BaseField>(x, point)?,
+            coordinate::<P::BaseField>(y, point)?,
+        );
+        let value = if x.is_zero() && y.is_zero() {
+            Affine::zero()
+        } else {
+            Affine::new_unchecked(x, y)
+        };
+        check_point(&value, point)?;
+
+        Ok(value)
+    }
+
+    impl GnarkG1Point {
+        fn to_ark<P: SWCurveConfig>(&self, point: PointId) -> Result<Affine<P>, GaragaError> {
+            point_from_coordinates(&[&self.x], &[&self.y], point)
+        }
+    }
+
+    impl GnarkG2Point {
+        fn to_ark<P: SWCurveConfig>(&self, point: PointId) -> Result<Affine<P>, GaragaError> {
+            point_from_coordinates(&[&self.x.a0, &self.x.a1], &[&self.y.a0, &self.y.a1], point)
+        }
+    }
+
+    /// The `.json` of a gnark `VerifyingKey`
+    ///
+    /// Only the fields Groth16 verifiers need are kept, and the commitment ones to refuse
+    /// keys using Pedersen commitments. Go writes empty slices as `null`.
+    #[derive(Clone, Debug, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkVerifyingKey {
+        pub g1: GnarkVerifyingKeyG1,
+        pub g2: GnarkVerifyingKeyG2,
+        #[serde(default)]
+        pub commitment_keys: Option<Vec<IgnoredAny>>,
+        #[serde(default)]
+        pub public_and_commitment_committed: Option<Vec<Vec<u64>>>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkVerifyingKeyG1 {
+        pub alpha: GnarkG1Point,
+        pub k: Vec<GnarkG1Point>,
+    }
+
+    #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkVerifyingKeyG2 {
+        pub beta: GnarkG2Point,
+        pub gamma: GnarkG2Point,
+        pub delta: GnarkG2Point,
+    }
+
+    /// The `.json` of a gnark `Proof`
+    #[derive(Clone, Debug, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    pub struct GnarkProof {
+        pub ar: GnarkG1Point,
+        pub bs: GnarkG2Point,
+        pub krs: GnarkG1Point,
+        #[serde(default)]
+        pub commitments: Option<Vec<IgnoredAny>>,
+    }
+
+    fn is_empty<T>(values: &Option<Vec<T>>) -> bool {
+        values.as_ref().is_none_or(Vec::is_empty)
+    }
+
+    impl GnarkVerifyingKey {
+        /// Fails if one of the points is invalid, or if the key uses Pedersen commitments
+        pub fn to_ark<E: GaragaCurve>(&self) -> Result<VerifyingKey<E>, GaragaError> {
+            if !is_empty(&self.commitment_keys) || !is_empty(&self.public_and_commitment_committed)
+            {
+                return Err(GaragaError::UnsupportedCommitments);
+            }
+
+            Ok(VerifyingKey {
+                alpha_g1: self.g1.alpha.to_ark(PointId::AlphaG1)?,
+                beta_g2: self.g2.beta.to_ark(PointId::BetaG2)?,
+                gamma_g2: self.g2.gamma.to_ark(PointId::GammaG2)?,
+                delta_g2: self.g2.delta.to_ark(PointId::DeltaG2)?,
+                gamma_abc_g1: self
+                    .g1
+                    .k
+                    .iter()
+                    .enumerate()
+                    .map(|(i, k)| k.to_ark(PointId::Ic(i)))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    impl GnarkProof {
+        /// Fails if one of the points is invalid, or if the proof holds Pedersen commitments
+        pub fn to_ark<E: GaragaCurve>(&self) -> Result<Proof<E>, GaragaError> {
+            if !is_empty(&self.commitments) {
+                return Err(GaragaError::UnsupportedCommitments);
+            }
+
+            Ok(Proof {
+                a: self.ar.to_ark(PointId::A)?,
+                b: self.bs.to_ark(PointId::B)?,
+                c: self.krs.to_ark(PointId::C)?,
+            })
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+pub use json::*;
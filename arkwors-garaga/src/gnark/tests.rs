@@ -0,0 +1,334 @@
+use ark_ec::{pairing::Pairing, AffineRepr};
+use ark_ff::PrimeField;
+use ark_groth16::Proof;
+
+use crate::{
+    test_utils::{field_bytes, point_bytes},
+    GaragaCurve,
+};
+
+/// The bytes `Proof.WriteTo` (compressed) or `WriteRawTo` would write for `proof`
+fn proof_bytes<E: GaragaCurve>(proof: &Proof<E>, compressed: bool) -> Vec<u8> {
+    let id = E::ELIPTIC_CURVE_ID;
+    [
+        point_bytes(&proof.a, id, compressed),
+        point_bytes(&proof.b, id, compressed),
+        point_bytes(&proof.c, id, compressed),
+        0u32.to_be_bytes().to_vec(),
+        point_bytes(&<E as Pairing>::G1Affine::zero(), id, compressed),
+    ]
+    .concat()
+}
+
+/// The bytes `Witness.WriteTo` would write for a witness
+fn witness_bytes<F: PrimeField>(public: &[F], secret: &[F]) -> Vec<u8> {
+    let mut bytes = (public.len() as u32).to_be_bytes().to_vec();
+    bytes.extend((secret.len() as u32).to_be_bytes());
+    bytes.extend(((public.len() + secret.len()) as u32).to_be_bytes());
+    for value in public.iter().chain(secret) {
+        bytes.extend(field_bytes(value));
+    }
+    bytes
+}
+
+mod read_verifying_key {
+    use ark_ec::AffineRepr;
+    use assert_matches::assert_matches;
+
+    use crate::test_utils::{point_bytes, verifying_key_bytes};
+    use crate::{
+        gnark::read_verifying_key, test_utils::valid_proof, ElipticCurveId, GaragaError,
+        Groth16VerifierConfiguration, InvalidPointReason, PointId,
+    };
+
+    #[test]
+    fn compressed_and_uncompressed() {
+        let (vk, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(3);
+        for compressed in [true, false] {
+            let bytes = verifying_key_bytes(&vk, compressed);
+            assert_eq!(read_verifying_key(bytes.as_slice()).unwrap(), vk);
+        }
+
+        let (vk, _, _) = valid_proof::<ark_bn254::Bn254>(2);
+        for compressed in [true, false] {
+            let bytes = verifying_key_bytes(&vk, compressed);
+            assert_eq!(
+                Groth16VerifierConfiguration::from_gnark(bytes.as_slice()).unwrap(),
+                Groth16VerifierConfiguration::from_verifying_key(vk.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn generator_encoding() {
+        // (1, 2), 2 being the smallest of 2 and -2
+        let generator = ark_bn254::G1Affine::generator();
+        let mut compressed = vec![0; 32];
+        compressed[0] = 0b1000_0000;
+        compressed[31] = 1;
+        assert_eq!(
+            point_bytes(&generator, ElipticCurveId::Bn254, true),
+            compressed
+        );
+        assert_eq!(
+            point_bytes(&-generator, ElipticCurveId::Bn254, true)[0],
+            0b1100_0000
+        );
+    }
+
+    #[test]
+    fn refuses_pedersen_commitments() {
+        let (vk, _, _) = valid_proof::<ark_bn254::Bn254>(1);
+        let mut bytes = verifying_key_bytes(&vk, true);
+
+        // One commitment key
+        let len = bytes.len();
+        bytes[len - 1] = 1;
+        assert_matches!(
+            read_verifying_key::<ark_bn254::Bn254>(bytes.as_slice()),
+            Err(GaragaError::UnsupportedCommitments)
+        );
+
+        // A commitment to the public input 1, `[[1]]`
+        bytes.truncate(len - 8);
+        bytes.extend(1u32.to_be_bytes());
+        bytes.extend(1u32.to_be_bytes());
+        bytes.extend(1u64.to_be_bytes());
+        bytes.extend(0u32.to_be_bytes());
+        assert_matches!(
+            read_verifying_key::<ark_bn254::Bn254>(bytes.as_slice()),
+            Err(GaragaError::UnsupportedCommitments)
+        );
+    }
+
+    #[test]
+    fn refuses_invalid_points() {
+        let (vk, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(1);
+
+        let mut bytes = verifying_key_bytes(&vk, true);
+        bytes[0] = 0b0010_0000 | (bytes[0] & 0b0001_1111);
+        assert_matches!(
+            read_verifying_key::<ark_bls12_381::Bls12_381>(bytes.as_slice()),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::AlphaG1,
+                reason: InvalidPointReason::InvalidEncoding
+            })
+        );
+
+        // The x coordinate of the G1 `β`, which is read but not used, set out of range
+        let mut bytes = verifying_key_bytes(&vk, false);
+        bytes[96..144].fill(0xff);
+        bytes[96] = 0b0001_1111;
+        assert_matches!(
+            read_verifying_key::<ark_bls12_381::Bls12_381>(bytes.as_slice()),
+            Ok(_)
+        );
+        bytes[192..240].copy_from_slice(&[0x11; 48]);
+        assert_matches!(
+            read_verifying_key::<ark_bls12_381::Bls12_381>(bytes.as_slice()),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::BetaG2,
+                reason: InvalidPointReason::NotOnCurve
+            })
+        );
+
+        let bytes = verifying_key_bytes(&vk, true);
+        assert_matches!(
+            read_verifying_key::<ark_bls12_381::Bls12_381>(&bytes[..bytes.len() - 1]),
+            Err(GaragaError::Io(_))
+        );
+    }
+}
+
+mod read_proof {
+    use ark_ec::AffineRepr;
+    use assert_matches::assert_matches;
+
+    use super::{proof_bytes, witness_bytes};
+    use crate::{
+        gnark::read_proof, test_utils::valid_proof, GaragaError, Groth16VerifierConfiguration,
+        Groth16VerifierInputs,
+    };
+
+    #[test]
+    fn compressed_and_uncompressed() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+        for compressed in [true, false] {
+            let bytes = proof_bytes(&proof, compressed);
+            assert_eq!(read_proof(bytes.as_slice()).unwrap(), proof);
+        }
+
+        let inputs = Groth16VerifierInputs::<ark_bls12_381::Bls12_381>::from_gnark(
+            proof_bytes(&proof, true).as_slice(),
+            witness_bytes(&public_inputs, &[]).as_slice(),
+        )
+        .unwrap();
+        inputs
+            .verify(&Groth16VerifierConfiguration::from_verifying_key(vk))
+            .unwrap();
+    }
+
+    #[test]
+    fn point_at_infinity() {
+        let (_, mut proof, _) = valid_proof::<ark_bn254::Bn254>(1);
+        proof.c = ark_bn254::G1Affine::zero();
+        for compressed in [true, false] {
+            let bytes = proof_bytes(&proof, compressed);
+            assert_eq!(read_proof(bytes.as_slice()).unwrap(), proof);
+        }
+    }
+
+    #[test]
+    fn refuses_pedersen_commitments() {
+        let (_, proof, _) = valid_proof::<ark_bn254::Bn254>(1);
+        let mut bytes = proof_bytes(&proof, true);
+        // The uint32 number of commitments, before the 32 bytes of their proof of knowledge
+        let len = bytes.len();
+        bytes[len - 33] = 1;
+
+        assert_matches!(
+            read_proof::<ark_bn254::Bn254>(bytes.as_slice()),
+            Err(GaragaError::UnsupportedCommitments)
+        );
+    }
+}
+
+mod read_public_witness {
+    use ark_std::{test_rng, UniformRand};
+    use assert_matches::assert_matches;
+
+    use super::witness_bytes;
+    use crate::{gnark::read_public_witness, GaragaError};
+
+    #[test]
+    fn public_and_full_witnesses() {
+        let rng = &mut test_rng();
+        let public = (0..3).map(|_| ark_bn254::Fr::rand(rng)).collect::<Vec<_>>();
+        let secret = [ark_bn254::Fr::rand(rng)];
+
+        let bytes = witness_bytes(&public, &[]);
+        assert_eq!(
+            read_public_witness::<ark_bn254::Fr>(bytes.as_slice()).unwrap(),
+            public
+        );
+        let bytes = witness_bytes(&public, &secret);
+        assert_eq!(
+            read_public_witness::<ark_bn254::Fr>(bytes.as_slice()).unwrap(),
+            public
+        );
+    }
+
+    #[test]
+    fn refuses_invalid_witnesses() {
+        let mut bytes = witness_bytes(&[ark_bls12_381::Fr::from(1u8)], &[]);
+        bytes[8..12].copy_from_slice(&2u32.to_be_bytes());
+        assert_matches!(
+            read_public_witness::<ark_bls12_381::Fr>(bytes.as_slice()),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+
+        let mut bytes = witness_bytes(&[ark_bls12_381::Fr::from(1u8)], &[]);
+        bytes[12..].copy_from_slice(&[0xff; 32]);
+        assert_matches!(
+            read_public_witness::<ark_bls12_381::Fr>(bytes.as_slice()),
+            Err(GaragaError::InvalidFieldElement(_))
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+mod gnark_verifying_key {
+    use ark_ec::AffineRepr;
+    use ark_ff::Field;
+    use assert_matches::assert_matches;
+    use num_bigint::BigUint;
+
+    use crate::{
+        gnark::{GnarkProof, GnarkVerifyingKey},
+        test_utils::valid_proof,
+        GaragaError, InvalidPointReason, PointId,
+    };
+
+    fn decimal<F: Field>(value: &F) -> Vec<String> {
+        value
+            .to_base_prime_field_elements()
+            .map(|c| format!("\"{}\"", Into::<BigUint>::into(c)))
+            .collect()
+    }
+
+    fn g1(point: &ark_bn254::G1Affine) -> String {
+        let (x, y) = point.xy().unwrap();
+        format!(r#"{{"X":{},"Y":{}}}"#, decimal(x)[0], decimal(y)[0])
+    }
+
+    fn g2(point: &ark_bn254::G2Affine) -> String {
+        let (x, y) = point.xy().unwrap();
+        let (x, y) = (decimal(x), decimal(y));
+        format!(
+            r#"{{"X":{{"A0":{},"A1":{}}},"Y":{{"A0":{},"A1":{}}}}}"#,
+            x[0], x[1], y[0], y[1]
+        )
+    }
+
+    #[test]
+    fn to_ark() {
+        let (vk, proof, _) = valid_proof::<ark_bn254::Bn254>(2);
+        let json = format!(
+            r#"{{
+                "G1": {{"Alpha": {}, "Beta": {{"X": 1, "Y": 2}}, "Delta": {{"X": 1, "Y": 2}}, "K": [{}]}},
+                "G2": {{"Beta": {}, "Delta": {}, "Gamma": {}}},
+                "CommitmentKeys": [],
+                "PublicAndCommitmentCommitted": null
+            }}"#,
+            g1(&vk.alpha_g1),
+            vk.gamma_abc_g1.iter().map(g1).collect::<Vec<_>>().join(","),
+            g2(&vk.beta_g2),
+            g2(&vk.delta_g2),
+            g2(&vk.gamma_g2),
+        );
+        let gnark_vk: GnarkVerifyingKey = serde_json::from_str(&json).unwrap();
+        assert_eq!(gnark_vk.to_ark::<ark_bn254::Bn254>().unwrap(), vk);
+
+        let json = format!(
+            r#"{{"Ar": {}, "Krs": {}, "Bs": {}, "Commitments": [], "CommitmentPok": {{"X": 0, "Y": 0}}}}"#,
+            g1(&proof.a),
+            g1(&proof.c),
+            g2(&proof.b),
+        );
+        let gnark_proof: GnarkProof = serde_json::from_str(&json).unwrap();
+        assert_eq!(gnark_proof.to_ark::<ark_bn254::Bn254>().unwrap(), proof);
+    }
+
+    #[test]
+    fn small_and_hex_elements() {
+        let json = r#"{"Ar": {"X": 1, "Y": "0x2"}, "Krs": {"X": 0, "Y": 0}, "Bs": {"X": {"A0": 0, "A1": 0}, "Y": {"A0": 0, "A1": 0}}}"#;
+        let gnark_proof: GnarkProof = serde_json::from_str(json).unwrap();
+        let proof = gnark_proof.to_ark::<ark_bn254::Bn254>().unwrap();
+
+        assert_eq!(proof.a, ark_bn254::G1Affine::generator());
+        assert!(proof.b.is_zero() && proof.c.is_zero());
+    }
+
+    #[test]
+    fn refuses_pedersen_commitments_and_invalid_points() {
+        let json = r#"{"Ar": {"X": 1, "Y": 2}, "Krs": {"X": 1, "Y": 2}, "Bs": {"X": {"A0": 0, "A1": 0}, "Y": {"A0": 0, "A1": 0}}, "Commitments": [{"X": 1, "Y": 2}]}"#;
+        let gnark_proof: GnarkProof = serde_json::from_str(json).unwrap();
+        assert_matches!(
+            gnark_proof.to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::UnsupportedCommitments)
+        );
+
+        let json = r#"{"Ar": {"X": 1, "Y": 3}, "Krs": {"X": 1, "Y": 2}, "Bs": {"X": {"A0": 0, "A1": 0}, "Y": {"A0": 0, "A1": 0}}}"#;
+        let gnark_proof: GnarkProof = serde_json::from_str(json).unwrap();
+        assert_matches!(
+            gnark_proof.to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::A,
+                reason: InvalidPointReason::NotOnCurve
+            })
+        );
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests;
+
+use ark_ff::{Fp12Config, Fp6Config, Zero};
+
+use crate::{pairing::Fq2, GaragaCurve};
+
+/// An element of the degree 12 extension field pairings land in, such as a Miller loop result
+///
+/// Arkworks builds this field as a tower `Fp2 -> Fp6 -> Fp12`. Garaga instead works with
+/// its direct representation `Fp[w] / (w¹² - 2·ξ₀·w⁶ + ξ₀² + 1)`, `ξ = ξ₀ + u` being the
+/// non residue of the tower (`9 + u` for BN254, `1 + u` for BLS12-381),
+/// and lists the coefficients of `w⁰` to `w¹¹`, which is how this type serializes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct GtElement<E: GaragaCurve>(E::TargetField);
+
+impl<E: GaragaCurve> GtElement<E> {
+    pub fn new(value: E::TargetField) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> &E::TargetField {
+        &self.0
+    }
+
+    /// The coefficients of `w⁰` to `w¹¹` in Garaga's direct representation
+    ///
+    /// An `Fp2` coefficient `x + y·u` of `w^j` in the tower contributes `x - ξ₀·y` to `w^j`
+    /// and `y` to `w^(j+6)`, as `u = w⁶ - ξ₀`.
+    pub fn coefficients(&self) -> [E::BaseField; 12] {
+        let xi0 = non_residue_c0::<E>();
+        let mut coefficients = [E::BaseField::zero(); 12];
+        for (j, a) in tower_coefficients::<E>(&self.0).into_iter().enumerate() {
+            coefficients[j] = a.c0 - xi0 * a.c1;
+            coefficients[j + 6] = a.c1;
+        }
+
+        coefficients
+    }
+
+    /// The inverse of `coefficients`
+    pub fn from_coefficients(coefficients: [E::BaseField; 12]) -> Self {
+        let xi0 = non_residue_c0::<E>();
+        let mut value = E::TargetField::zero();
+        for (j, a) in tower_coefficients_mut::<E>(&mut value)
+            .into_iter()
+            .enumerate()
+        {
+            a.c1 = coefficients[j + 6];
+            a.c0 = coefficients[j] + xi0 * a.c1;
+        }
+
+        Self(value)
+    }
+}
+
+/// `ξ₀`, the real part of the non residue `ξ = w⁶` of the tower
+fn non_residue_c0<E: GaragaCurve>() -> E::BaseField {
+    <<E::Fp12Config as Fp12Config>::Fp6Config as Fp6Config>::NONRESIDUE.c0
+}
+
+/// The `Fp2` coefficients of `value` in the tower, the `j`-th one being the coefficient of `w^j`
+fn tower_coefficients<E: GaragaCurve>(value: &E::TargetField) -> [Fq2<E::Fp12Config>; 6] {
+    // c0 = a0 + a1·v + a2·v², c1 = (b0 + b1·v + b2·v²)·w, with v = w²
+    [
+        value.c0.c0,
+        value.c1.c0,
+        value.c0.c1,
+        value.c1.c1,
+        value.c0.c2,
+        value.c1.c2,
+    ]
+}
+
+fn tower_coefficients_mut<E: GaragaCurve>(
+    value: &mut E::TargetField,
+) -> [&mut Fq2<E::Fp12Config>; 6] {
+    let (c0, c1) = (&mut value.c0, &mut value.c1);
+    [
+        &mut c0.c0, &mut c1.c0, &mut c0.c1, &mut c1.c1, &mut c0.c2, &mut c1.c2,
+    ]
+}
+
+#[cfg(feature = "serde")]
+mod impl_serde {
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::GtElement;
+    use crate::{serde::E12, GaragaCurve};
+
+    impl<E: GaragaCurve> Serialize for GtElement<E> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let e12 = E12::try_from(self).map_err(ser::Error::custom)?;
+            e12.serialize(serializer)
+        }
+    }
+
+    impl<'de, E: GaragaCurve> Deserialize<'de> for GtElement<E> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let e12 = E12::deserialize(deserializer)?;
+            e12.to_ark::<E>().map_err(de::Error::custom)
+        }
+    }
+}
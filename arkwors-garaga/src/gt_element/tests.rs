@@ -0,0 +1,82 @@
+mod gt_element {
+    use ark_ff::{Field, One, Zero};
+    use ark_std::{test_rng, UniformRand};
+
+    use crate::{GaragaCurve, GtElement};
+
+    /// `a·b` in `Fp[w] / (w¹² - 2·ξ₀·w⁶ + ξ₀² + 1)`
+    fn direct_mul<E: GaragaCurve>(
+        a: &[E::BaseField; 12],
+        b: &[E::BaseField; 12],
+        xi0: E::BaseField,
+    ) -> [E::BaseField; 12] {
+        let mut product = [E::BaseField::zero(); 23];
+        for (i, a) in a.iter().enumerate() {
+            for (j, b) in b.iter().enumerate() {
+                product[i + j] += *a * b;
+            }
+        }
+        // w¹² = 2·ξ₀·w⁶ - (ξ₀² + 1)
+        for k in (12..23).rev() {
+            let c = product[k];
+            product[k - 6] += c * xi0.double();
+            product[k - 12] -= c * (xi0.square() + E::BaseField::one());
+        }
+
+        product[..12].try_into().unwrap()
+    }
+
+    fn coefficients_for<E: GaragaCurve>(xi0: u8) {
+        let rng = &mut test_rng();
+        let a = E::TargetField::rand(rng);
+        let b = E::TargetField::rand(rng);
+
+        let one = GtElement::<E>::new(E::TargetField::one()).coefficients();
+        assert!(one[0].is_one() && one[1..].iter().all(Zero::is_zero));
+
+        let (a, b) = (GtElement::<E>::new(a), GtElement::<E>::new(b));
+        assert_eq!(GtElement::from_coefficients(a.coefficients()), a);
+        assert_eq!(
+            GtElement::<E>::new(*a.value() * b.value()).coefficients(),
+            direct_mul::<E>(&a.coefficients(), &b.coefficients(), xi0.into())
+        );
+    }
+
+    #[test]
+    fn coefficients() {
+        coefficients_for::<ark_bn254::Bn254>(9);
+        coefficients_for::<ark_bls12_381::Bls12_381>(1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialization() {
+        let one = GtElement::<ark_bn254::Bn254>::new(ark_bn254::Fq12::one());
+        assert_eq!(
+            serde_json::to_string(&one).unwrap(),
+            r#"["0x1","0x0","0x0","0x0","0x0","0x0","0x0","0x0","0x0","0x0","0x0","0x0"]"#
+        );
+
+        let value =
+            GtElement::<ark_bls12_381::Bls12_381>::new(ark_bls12_381::Fq12::rand(&mut test_rng()));
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<GtElement<_>>(&json).unwrap(), value);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_invalid_coefficients() {
+        let mut json = vec!["0x0"; 12];
+        json[3] = "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+
+        let err = serde_json::from_value::<GtElement<ark_bn254::Bn254>>(json.into()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47` is not a valid field element"
+        );
+
+        assert!(
+            serde_json::from_value::<GtElement<ark_bn254::Bn254>>(vec!["0x0"; 11].into()).is_err()
+        );
+    }
+}
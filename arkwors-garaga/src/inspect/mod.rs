@@ -0,0 +1,259 @@
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+
+use ark_serialize::CanonicalSerialize;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    ElipticCurveId, GaragaCurve, Groth16VerifierConfiguration, Groth16VerifierInputs, PointId,
+};
+
+/// The SHA-256 hash of the compressed Arkworks serialization of a point
+///
+/// It displays as the hex of its first 8 bytes, enough to tell points apart at a glance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Fingerprint([u8; 32]);
+
+impl Fingerprint {
+    pub fn of(point: &impl CanonicalSerialize) -> Self {
+        let mut bytes = Vec::with_capacity(point.compressed_size());
+        point
+            .serialize_compressed(&mut bytes)
+            .expect("writing to a `Vec` doesn't fail");
+
+        Self(Sha256::digest(bytes).into())
+    }
+
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0[..8].iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+/// What `Groth16VerifierConfiguration::inspect` reports: the curve and the fingerprint of each
+/// point of the verifying key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConfigurationReport {
+    eliptic_curve_id: ElipticCurveId,
+    points: Vec<(PointId, Fingerprint)>,
+}
+
+impl ConfigurationReport {
+    pub fn eliptic_curve_id(&self) -> ElipticCurveId {
+        self.eliptic_curve_id
+    }
+
+    /// One less than the number of `ic` points, `ic[i + 1]` is scaled by the `i`th public input
+    pub fn n_public_inputs(&self) -> usize {
+        self.ic_count().saturating_sub(1)
+    }
+
+    /// The fingerprints of `alpha_g1`, `beta_g2`, `gamma_g2`, `delta_g2` then of the `ic` points
+    pub fn points(&self) -> &Vec<(PointId, Fingerprint)> {
+        &self.points
+    }
+
+    pub fn fingerprint(&self, point: PointId) -> Option<Fingerprint> {
+        self.points
+            .iter()
+            .find(|(id, _)| *id == point)
+            .map(|(_, fingerprint)| *fingerprint)
+    }
+
+    fn ic_count(&self) -> usize {
+        self.points
+            .iter()
+            .filter(|(id, _)| matches!(id, PointId::Ic(_)))
+            .count()
+    }
+
+    /// The elements of the key that differ in `other`
+    pub fn diff(&self, other: &Self) -> ConfigurationDiff {
+        let mut diff = ConfigurationDiff::default();
+        if self.eliptic_curve_id != other.eliptic_curve_id {
+            diff.eliptic_curve_id = Some((self.eliptic_curve_id, other.eliptic_curve_id));
+        }
+        if self.n_public_inputs() != other.n_public_inputs() {
+            diff.n_public_inputs = Some((self.n_public_inputs(), other.n_public_inputs()));
+        }
+        for (point, fingerprint) in &self.points {
+            match other.fingerprint(*point) {
+                Some(other) if other == *fingerprint => {}
+                Some(_) => diff.changed.push(*point),
+                None => diff.removed.push(*point),
+            }
+        }
+        diff.added = other
+            .points
+            .iter()
+            .filter(|(point, _)| self.fingerprint(*point).is_none())
+            .map(|(point, _)| *point)
+            .collect();
+
+        diff
+    }
+}
+
+/// One line per element, stating which public input each `ic` point pairs with
+impl fmt::Display for ConfigurationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "curve: {}", self.eliptic_curve_id)?;
+        writeln!(f, "public inputs: {}", self.n_public_inputs())?;
+        for (point, fingerprint) in &self.points {
+            match point {
+                PointId::Ic(0) => writeln!(f, "{point}: {fingerprint} (constant term)")?,
+                PointId::Ic(i) => writeln!(f, "{point}: {fingerprint} (public input {})", i - 1)?,
+                _ => writeln!(f, "{point}: {fingerprint}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// What `Groth16VerifierInputs::inspect` reports: the curve, the fingerprint of each point of the
+/// proof and the public inputs
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputsReport {
+    eliptic_curve_id: ElipticCurveId,
+    points: Vec<(PointId, Fingerprint)>,
+    public_inputs: Vec<String>,
+}
+
+impl InputsReport {
+    pub fn eliptic_curve_id(&self) -> ElipticCurveId {
+        self.eliptic_curve_id
+    }
+
+    pub fn n_public_inputs(&self) -> usize {
+        self.public_inputs.len()
+    }
+
+    /// The fingerprints of `a`, `b` and `c`
+    pub fn points(&self) -> &Vec<(PointId, Fingerprint)> {
+        &self.points
+    }
+
+    /// The public inputs in decimal, the `i`th one pairs with `ic[i + 1]`
+    pub fn public_inputs(&self) -> &Vec<String> {
+        &self.public_inputs
+    }
+}
+
+impl fmt::Display for InputsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "curve: {}", self.eliptic_curve_id)?;
+        writeln!(f, "public inputs: {}", self.n_public_inputs())?;
+        for (point, fingerprint) in &self.points {
+            writeln!(f, "{point}: {fingerprint}")?;
+        }
+        for (i, value) in self.public_inputs.iter().enumerate() {
+            writeln!(f, "public input {i}: {value} (ic[{}])", i + 1)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The differences between two configurations, see `ConfigurationReport::diff`
+///
+/// Points are compared through their fingerprints.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigurationDiff {
+    /// The curves of both configurations, if they differ
+    pub eliptic_curve_id: Option<(ElipticCurveId, ElipticCurveId)>,
+    /// The numbers of public inputs of both configurations, if they differ
+    pub n_public_inputs: Option<(usize, usize)>,
+    /// The elements both keys have, with different values
+    pub changed: Vec<PointId>,
+    /// The `ic` points only the second key has
+    pub added: Vec<PointId>,
+    /// The `ic` points only the first key has
+    pub removed: Vec<PointId>,
+}
+
+impl ConfigurationDiff {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl fmt::Display for ConfigurationDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((old, new)) = self.eliptic_curve_id {
+            writeln!(f, "curve: {old} -> {new}")?;
+        }
+        if let Some((old, new)) = self.n_public_inputs {
+            writeln!(f, "public inputs: {old} -> {new}")?;
+        }
+        for (label, points) in [
+            ("changed", &self.changed),
+            ("added", &self.added),
+            ("removed", &self.removed),
+        ] {
+            for point in points {
+                writeln!(f, "{label}: {point}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Fingerprint the elements of the verifying key, to compare keys without reading their
+    /// coordinates
+    pub fn inspect(&self) -> ConfigurationReport {
+        let vk = self.verifying_key();
+        let mut points = vec![(PointId::AlphaG1, Fingerprint::of(&vk.alpha_g1))];
+        for (point, value) in [
+            (PointId::BetaG2, &vk.beta_g2),
+            (PointId::GammaG2, &vk.gamma_g2),
+            (PointId::DeltaG2, &vk.delta_g2),
+        ] {
+            points.push((point, Fingerprint::of(value)));
+        }
+        for (i, ic) in vk.gamma_abc_g1.iter().enumerate() {
+            points.push((PointId::Ic(i), Fingerprint::of(ic)));
+        }
+
+        ConfigurationReport {
+            eliptic_curve_id: E::ELIPTIC_CURVE_ID,
+            points,
+        }
+    }
+
+    /// Same as `ConfigurationReport::diff` on the reports of both configurations
+    pub fn diff(&self, other: &Self) -> ConfigurationDiff {
+        self.inspect().diff(&other.inspect())
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierInputs<E> {
+    /// Fingerprint the points of the proof and list the public inputs
+    pub fn inspect(&self) -> InputsReport {
+        let proof = self.proof();
+
+        InputsReport {
+            eliptic_curve_id: E::ELIPTIC_CURVE_ID,
+            points: vec![
+                (PointId::A, Fingerprint::of(&proof.a)),
+                (PointId::B, Fingerprint::of(&proof.b)),
+                (PointId::C, Fingerprint::of(&proof.c)),
+            ],
+            public_inputs: self
+                .public_inputs()
+                .iter()
+                .map(|v| Into::<BigUint>::into(*v).to_string())
+                .collect(),
+        }
+    }
+}
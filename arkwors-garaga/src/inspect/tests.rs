@@ -0,0 +1,123 @@
+mod groth_16_verifier_configuration {
+    use ark_ec::AffineRepr;
+
+    use crate::{
+        test_utils::valid_proof, AnyGroth16VerifierConfiguration, ConfigurationDiff,
+        ElipticCurveId, Fingerprint, Groth16VerifierConfiguration, PointId,
+    };
+
+    #[test]
+    fn inspect_pairs_ic_with_public_inputs() {
+        let (vk, _, _) = valid_proof::<ark_bn254::Bn254>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk.clone());
+
+        let report = config.inspect();
+        assert_eq!(report.eliptic_curve_id(), ElipticCurveId::Bn254);
+        assert_eq!(report.n_public_inputs(), 2);
+        assert_eq!(
+            report.fingerprint(PointId::Ic(1)),
+            Some(Fingerprint::of(&vk.gamma_abc_g1[1]))
+        );
+        assert_eq!(report.fingerprint(PointId::Ic(3)), None);
+
+        let lines = report.to_string();
+        let lines = lines.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[..2], ["curve: bn254", "public inputs: 2"]);
+        assert_eq!(
+            lines[6],
+            format!(
+                "ic[0]: {} (constant term)",
+                Fingerprint::of(&vk.gamma_abc_g1[0])
+            )
+        );
+        assert!(lines[8].starts_with("ic[2]: ") && lines[8].ends_with(" (public input 1)"));
+    }
+
+    #[test]
+    fn diff_lists_changed_elements() {
+        let (vk, _, _) = valid_proof::<ark_bn254::Bn254>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk.clone());
+        assert!(config.diff(&config).is_empty());
+
+        let mut other = vk.clone();
+        other.delta_g2 = vk.gamma_g2;
+        other.gamma_abc_g1[1] = vk.gamma_abc_g1[2];
+        other.gamma_abc_g1.push(ark_bn254::G1Affine::generator());
+        let diff = config.diff(&Groth16VerifierConfiguration::from_verifying_key(other));
+
+        assert_eq!(
+            diff,
+            ConfigurationDiff {
+                eliptic_curve_id: None,
+                n_public_inputs: Some((2, 3)),
+                changed: vec![PointId::DeltaG2, PointId::Ic(1)],
+                added: vec![PointId::Ic(3)],
+                removed: vec![],
+            }
+        );
+        assert_eq!(
+            diff.to_string(),
+            "public inputs: 2 -> 3\nchanged: delta_g2\nchanged: ic[1]\nadded: ic[3]\n"
+        );
+    }
+
+    #[test]
+    fn diff_across_curves() {
+        let (bn254, _, _) = valid_proof::<ark_bn254::Bn254>(1);
+        let (bls12_381, _, _) = valid_proof::<ark_bls12_381::Bls12_381>(1);
+        let bn254: AnyGroth16VerifierConfiguration =
+            Groth16VerifierConfiguration::from_verifying_key(bn254).into();
+        let bls12_381: AnyGroth16VerifierConfiguration =
+            Groth16VerifierConfiguration::from_verifying_key(bls12_381).into();
+
+        let diff = bn254.diff(&bls12_381);
+        assert_eq!(
+            diff.eliptic_curve_id,
+            Some((ElipticCurveId::Bn254, ElipticCurveId::Bls12_381))
+        );
+        assert_eq!(diff.n_public_inputs, None);
+        assert_eq!(diff.changed.len(), 6);
+    }
+}
+
+mod groth_16_verifier_inputs {
+    use crate::{test_utils::valid_proof, Fingerprint, Groth16VerifierInputs, PointId};
+
+    #[test]
+    fn inspect_lists_public_inputs() {
+        let (_, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+        let inputs = Groth16VerifierInputs::from_proof(proof.clone(), public_inputs.clone());
+
+        let report = inputs.inspect();
+        assert_eq!(report.n_public_inputs(), 2);
+        assert_eq!(
+            report.points(),
+            &vec![
+                (PointId::A, Fingerprint::of(&proof.a)),
+                (PointId::B, Fingerprint::of(&proof.b)),
+                (PointId::C, Fingerprint::of(&proof.c)),
+            ]
+        );
+        assert_eq!(
+            report.to_string().lines().last().unwrap(),
+            format!("public input 1: {} (ic[2])", public_inputs[1])
+        );
+    }
+
+    #[test]
+    fn inspect_prints_zero_public_inputs() {
+        let (_, proof, _) = valid_proof::<ark_bn254::Bn254>(2);
+        let public_inputs = vec![ark_bn254::Fr::from(0u8), ark_bn254::Fr::from(7u8)];
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+
+        let report = inputs.inspect();
+        assert_eq!(
+            report.public_inputs(),
+            &vec!["0".to_string(), "7".to_string()]
+        );
+        assert!(report
+            .to_string()
+            .ends_with("public input 0: 0 (ic[1])\npublic input 1: 7 (ic[2])\n"));
+    }
+}
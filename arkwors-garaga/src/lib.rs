@@ -1,16 +1,68 @@
-/// This crate provide logic to (de)serialize Arkworks outputs as valid Garaga inputs  
-///
-/// In order to generate the Starket verifier contract, Garaga expect a .json containing:
-/// - the id of the eliptic curve used  
-/// - the verifying key for the circuit
-/// The `Groth16VerifierConfiguration` allow for this serialization.
-///
-/// In order to generate the payload for verifying a specifc run of the circuit, Garaga expect a .json containing:
-/// - the proof
-/// - the public inputs
+//! This crate provide logic to (de)serialize Arkworks outputs as valid Garaga inputs  
+//!
+//! In order to generate the Starket verifier contract, Garaga expect a .json containing:
+//! - the id of the eliptic curve used  
+//! - the verifying key for the circuit
+//!
+//! The `Groth16VerifierConfiguration` allow for this serialization.
+//!
+//! In order to generate the payload for verifying a specifc run of the circuit, Garaga expect a .json containing:
+//! - the proof
+//! - the public inputs
+//!
+//! The `calldata` module encodes proofs as the `felt252`s Garaga's verifier contracts start their
+//! calldata with, the hints that follow are not computed.
+//!
+//! `Groth16VerifierConfiguration::cairo_constants` writes the constants module of the Cairo verifier,
+//! which Garaga's generator would otherwise produce from the configuration `.json`,
+//! and the `scarb` module scaffolds a whole Scarb package around it.
+//!
+//! The `snarkjs` module reads and writes the Groth16 files of snarkjs,
+//! and the `gnark` module reads the Groth16 keys, proofs and public witnesses of gnark.
+//! The `circom` module reads the `.zkey` proving keys, `.r1cs` constraint systems and `.wtns`
+//! witnesses of Circom circuits, to set them up and prove them with Arkworks.
+//!
+//! The `risc0` module turns RISC Zero Groth16 receipts into inputs for its fixed verifying key,
+//! and the `sp1` module does the same for SP1 Groth16 proofs.
+//!
+//! With the `cli` feature, the `arkworks-garaga` binary writes the `.json` files from Arkworks
+//! or snarkjs keys and proofs, checks proofs, prints the calldata of proofs and writes Scarb packages.
+//!
+//! `Groth16VerifierConfiguration::inspect` and `diff` fingerprint the elements of verifying keys
+//! to tell which of them two keys disagree on.
 
+mod any;
+pub use any::*;
+mod audit;
+pub use audit::*;
+mod bundle;
+pub use bundle::*;
+mod cairo;
+#[cfg(feature = "calldata")]
+pub mod calldata;
+pub mod circom;
+mod error;
+pub use error::*;
+pub mod gnark;
+mod gt_element;
+pub use gt_element::*;
+mod inspect;
+pub use inspect::*;
+mod mpcheck;
+pub use mpcheck::*;
+mod msm;
+pub use msm::*;
+mod pairing;
+pub use pairing::G2Line;
+pub mod risc0;
+pub mod scarb;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde")]
+pub mod snarkjs;
+pub mod sp1;
+#[cfg(test)]
+mod test_utils;
 mod verifier_config;
 pub use verifier_config::*;
 mod verifier_inputs;
@@ -19,10 +71,125 @@ pub use verifier_inputs::*;
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(
     feature = "serde",
-    derive(::serde::Serialize),
+    derive(::serde::Serialize, ::serde::Deserialize),
     serde(rename_all = "snake_case")
 )]
 pub enum ElipticCurveId {
     Bn254,
     Bls12_381,
 }
+
+/// Displays as its serialized name, `bn254` or `bls12_381`
+impl std::fmt::Display for ElipticCurveId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ElipticCurveId::Bn254 => write!(f, "bn254"),
+            ElipticCurveId::Bls12_381 => write!(f, "bls12_381"),
+        }
+    }
+}
+
+impl std::str::FromStr for ElipticCurveId {
+    type Err = GaragaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bn254" => Ok(ElipticCurveId::Bn254),
+            "bls12_381" => Ok(ElipticCurveId::Bls12_381),
+            _ => Err(GaragaError::InvalidElipticCurveId(s.to_string())),
+        }
+    }
+}
+
+/// A pairing engine supported by Garaga
+///
+/// It ties the arkworks type to the `ElipticCurveId` Garaga expects for it,
+/// so that the id can be inferred rather than passed around separately.
+/// It is sealed, as the hints Garaga's verifiers take are computed by curve specific code.
+pub trait GaragaCurve: pairing::PairingCurve {
+    const ELIPTIC_CURVE_ID: ElipticCurveId;
+}
+
+impl GaragaCurve for ark_bn254::Bn254 {
+    const ELIPTIC_CURVE_ID: ElipticCurveId = ElipticCurveId::Bn254;
+}
+
+impl GaragaCurve for ark_bls12_381::Bls12_381 {
+    const ELIPTIC_CURVE_ID: ElipticCurveId = ElipticCurveId::Bls12_381;
+}
+
+/// Make sure `eliptic_curve_id` is the one Garaga associates with `E`
+pub(crate) fn check_eliptic_curve_id<E: GaragaCurve>(
+    eliptic_curve_id: ElipticCurveId,
+) -> Result<(), GaragaError> {
+    if eliptic_curve_id != E::ELIPTIC_CURVE_ID {
+        return Err(GaragaError::CurveMismatch {
+            expected: E::ELIPTIC_CURVE_ID,
+            found: eliptic_curve_id,
+        });
+    }
+
+    Ok(())
+}
+
+/// Make sure `vk` expects `count` public inputs, that is one less than its number of `ic` points
+///
+/// A key without any `ic` point, as `VerifyingKey::default()`, expects none.
+pub(crate) fn check_public_input_count<E: ark_ec::pairing::Pairing>(
+    vk: &ark_groth16::VerifyingKey<E>,
+    count: usize,
+) -> Result<(), GaragaError> {
+    if vk.gamma_abc_g1.is_empty() {
+        return Err(GaragaError::NoIc);
+    }
+    let expected = vk.gamma_abc_g1.len() - 1;
+    if count != expected {
+        return Err(GaragaError::PublicInputCountMismatch {
+            expected,
+            found: count,
+        });
+    }
+
+    Ok(())
+}
+
+/// Make sure `value` is not the point at infinity
+///
+/// A proof element at infinity is never legitimate, and writing it with Garaga's
+/// `(0, 0)` encoding of infinity would only get it rejected on chain.
+pub(crate) fn check_not_at_infinity<A: ark_ec::AffineRepr>(
+    value: &A,
+    point: PointId,
+) -> Result<(), GaragaError> {
+    if value.is_zero() {
+        return Err(GaragaError::InvalidPoint {
+            point,
+            reason: InvalidPointReason::AtInfinity,
+        });
+    }
+
+    Ok(())
+}
+
+/// Make sure `value` is on the curve and in its prime order subgroup
+///
+/// Both matter: G2 of BN254 and both groups of BLS12-381 have non trivial cofactors.
+pub(crate) fn check_point<P: ark_ec::short_weierstrass::SWCurveConfig>(
+    value: &ark_ec::short_weierstrass::Affine<P>,
+    point: PointId,
+) -> Result<(), GaragaError> {
+    if !value.is_on_curve() {
+        return Err(GaragaError::InvalidPoint {
+            point,
+            reason: InvalidPointReason::NotOnCurve,
+        });
+    }
+    if !value.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(GaragaError::InvalidPoint {
+            point,
+            reason: InvalidPointReason::NotInSubgroup,
+        });
+    }
+
+    Ok(())
+}
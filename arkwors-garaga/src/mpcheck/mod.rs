@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod tests;
+
+use ark_ff::Field;
+use ark_groth16::{Proof, VerifyingKey};
+
+use crate::{
+    pairing::{checked_lines, multi_miller_loop, residue_witness},
+    GaragaCurve, GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs, GtElement,
+    PointId, PublicInputMsm,
+};
+
+/// The hints of Garaga's multi-pairing check (MPCheck)
+///
+/// Garaga's verifiers don't run the final exponentiation. For the Miller loop result `f` of
+/// `e(vk_x, γ)·e(C, δ)·e(-A, B)·e(α, β)` they instead check `f · w = c^λ`,
+/// `c` being the `lambda_root` and `w` the `scaling_factor`,
+/// which holds iff the final exponentiation of `f` is one.
+/// They are `GtElement`s, listed in Garaga's direct representation by `coefficients`.
+///
+/// The quotient polynomials of the Miller loop steps Garaga also expects are not part of it:
+/// they depend on the challenges Garaga draws from its Poseidon transcript,
+/// which this crate does not reproduce.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MPCheckHint<E: GaragaCurve> {
+    lambda_root: GtElement<E>,
+    lambda_root_inverse: GtElement<E>,
+    scaling_factor: GtElement<E>,
+}
+
+impl<E: GaragaCurve> MPCheckHint<E> {
+    /// Fails if the number of public inputs doesn't match `vk`, if one of the G2 points is not
+    /// in the prime order subgroup, or if the proof doesn't verify, in which case no such hint exists
+    pub fn new(
+        vk: &VerifyingKey<E>,
+        proof: &Proof<E>,
+        public_inputs: &[E::ScalarField],
+    ) -> Result<Self, GaragaError> {
+        let vk_x = *PublicInputMsm::new(vk, public_inputs)?.result();
+
+        let gamma_lines = checked_lines(&vk.gamma_g2, PointId::GammaG2)?;
+        let delta_lines = checked_lines(&vk.delta_g2, PointId::DeltaG2)?;
+        let b_lines = checked_lines(&proof.b, PointId::B)?;
+        let beta_lines = checked_lines(&vk.beta_g2, PointId::BetaG2)?;
+
+        let f = multi_miller_loop::<E>(&[
+            (vk_x, &gamma_lines),
+            (proof.c, &delta_lines),
+            (-proof.a, &b_lines),
+            (vk.alpha_g1, &beta_lines),
+        ]);
+
+        let (lambda_root, scaling_factor) =
+            residue_witness::<E>(&f).ok_or(GaragaError::PairingCheckFailed)?;
+        let lambda_root_inverse = lambda_root
+            .inverse()
+            .ok_or(GaragaError::PairingCheckFailed)?;
+
+        Ok(Self {
+            lambda_root: GtElement::new(lambda_root),
+            lambda_root_inverse: GtElement::new(lambda_root_inverse),
+            scaling_factor: GtElement::new(scaling_factor),
+        })
+    }
+}
+
+impl<E: GaragaCurve> MPCheckHint<E> {
+    pub fn lambda_root(&self) -> &GtElement<E> {
+        &self.lambda_root
+    }
+
+    pub fn lambda_root_inverse(&self) -> &GtElement<E> {
+        &self.lambda_root_inverse
+    }
+
+    pub fn scaling_factor(&self) -> &GtElement<E> {
+        &self.scaling_factor
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierInputs<E> {
+    /// Same as `MPCheckHint::new`, for this proof and the verifying key of `config`
+    pub fn mpcheck_hint(
+        &self,
+        config: &Groth16VerifierConfiguration<E>,
+    ) -> Result<MPCheckHint<E>, GaragaError> {
+        MPCheckHint::new(config.verifying_key(), self.proof(), self.public_inputs())
+    }
+}
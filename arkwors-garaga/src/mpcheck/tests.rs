@@ -0,0 +1,62 @@
+mod mpcheck_hint {
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::One;
+    use ark_groth16::VerifyingKey;
+    use assert_matches::assert_matches;
+
+    use crate::{
+        test_utils::valid_proof, GaragaCurve, GaragaError, Groth16VerifierConfiguration,
+        Groth16VerifierInputs, MPCheckHint,
+    };
+
+    fn new_for<E: GaragaCurve>() {
+        let (vk, proof, public_inputs) = valid_proof::<E>(3);
+
+        let hint = MPCheckHint::new(&vk, &proof, &public_inputs).unwrap();
+        assert!((*hint.lambda_root().value() * hint.lambda_root_inverse().value()).is_one());
+
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        assert_eq!(inputs.mpcheck_hint(&config).unwrap(), hint);
+    }
+
+    #[test]
+    fn new() {
+        new_for::<ark_bn254::Bn254>();
+        new_for::<ark_bls12_381::Bls12_381>();
+    }
+
+    #[test]
+    fn new_rejects_invalid_proof() {
+        let (vk, mut proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(2);
+        proof.c = (proof.c + ark_bn254::G1Affine::generator()).into_affine();
+
+        assert_matches!(
+            MPCheckHint::new(&vk, &proof, &public_inputs),
+            Err(GaragaError::PairingCheckFailed)
+        );
+    }
+
+    #[test]
+    fn new_rejects_public_input_count_mismatch() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(2);
+
+        assert_matches!(
+            MPCheckHint::new(&vk, &proof, &public_inputs[1..]),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn new_rejects_key_without_ic() {
+        let (_, proof, _) = valid_proof::<ark_bn254::Bn254>(0);
+
+        assert_matches!(
+            MPCheckHint::new(&VerifyingKey::default(), &proof, &[]),
+            Err(GaragaError::NoIc)
+        );
+    }
+}
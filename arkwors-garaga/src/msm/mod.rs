@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests;
+
+use ark_ec::{pairing::Pairing, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_groth16::VerifyingKey;
+
+use crate::{
+    check_public_input_count, GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+};
+
+/// The multi-scalar multiplication `vk_x = ic[0] + Σ input_i · ic[i + 1]`, split as Garaga's
+/// verifiers split it
+///
+/// Garaga's verifiers take the scalars as `u256`s and split each of them into its low and
+/// high 128 bits, running two MSMs of half the size:
+/// `vk_x = ic[0] + Q_low + Q_high_shifted`, with `Q_high_shifted = 2^128 · Q_high`.
+///
+/// It is not Garaga's `msm_hint`: that one also holds the function field witnesses
+/// (`SumDlogDiv`) of both MSMs, evaluated at a random point drawn from Garaga's Poseidon
+/// transcript, which this crate does not reproduce.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicInputMsm<E: Pairing> {
+    scalars_low: Vec<u128>,
+    scalars_high: Vec<u128>,
+    q_low: E::G1Affine,
+    q_high: E::G1Affine,
+    q_high_shifted: E::G1Affine,
+    result: E::G1Affine,
+}
+
+impl<E: Pairing> PublicInputMsm<E> {
+    /// Fails if the number of public inputs doesn't match `vk`
+    pub fn new(
+        vk: &VerifyingKey<E>,
+        public_inputs: &[E::ScalarField],
+    ) -> Result<Self, GaragaError> {
+        check_public_input_count(vk, public_inputs.len())?;
+
+        let (scalars_low, scalars_high): (Vec<_>, Vec<_>) =
+            public_inputs.iter().map(split_u128).unzip();
+        let msm = |scalars: &[u128]| {
+            let scalars = scalars
+                .iter()
+                .map(|s| E::ScalarField::from(*s))
+                .collect::<Vec<_>>();
+            E::G1::msm_unchecked(&vk.gamma_abc_g1[1..], &scalars)
+        };
+        let q_low = msm(&scalars_low);
+        let q_high = msm(&scalars_high);
+        let q_high_shifted = q_high * E::ScalarField::from(2u8).pow([128]);
+        let result = q_low + q_high_shifted + vk.gamma_abc_g1[0];
+
+        Ok(Self {
+            scalars_low,
+            scalars_high,
+            q_low: q_low.into_affine(),
+            q_high: q_high.into_affine(),
+            q_high_shifted: q_high_shifted.into_affine(),
+            result: result.into_affine(),
+        })
+    }
+
+    /// The low 128 bits of each public input
+    pub fn scalars_low(&self) -> &Vec<u128> {
+        &self.scalars_low
+    }
+
+    /// The high 128 bits of each public input
+    pub fn scalars_high(&self) -> &Vec<u128> {
+        &self.scalars_high
+    }
+
+    /// `Σ low_i · ic[i + 1]`
+    pub fn q_low(&self) -> &E::G1Affine {
+        &self.q_low
+    }
+
+    /// `Σ high_i · ic[i + 1]`
+    pub fn q_high(&self) -> &E::G1Affine {
+        &self.q_high
+    }
+
+    /// `2^128 · Q_high`
+    pub fn q_high_shifted(&self) -> &E::G1Affine {
+        &self.q_high_shifted
+    }
+
+    /// `vk_x`, the point paired with `gamma_g2`
+    pub fn result(&self) -> &E::G1Affine {
+        &self.result
+    }
+}
+
+/// The low and high 128 bits of `scalar`
+fn split_u128<F: PrimeField>(scalar: &F) -> (u128, u128) {
+    let bigint = scalar.into_bigint();
+    let limb = |i: usize| bigint.as_ref().get(i).copied().unwrap_or(0) as u128;
+    debug_assert!(bigint.num_bits() <= 256);
+
+    (limb(0) | limb(1) << 64, limb(2) | limb(3) << 64)
+}
+
+impl<E: Pairing> Groth16VerifierInputs<E> {
+    /// Same as `PublicInputMsm::new`, for these public inputs and the verifying key of `config`
+    pub fn public_input_msm(
+        &self,
+        config: &Groth16VerifierConfiguration<E>,
+    ) -> Result<PublicInputMsm<E>, GaragaError> {
+        PublicInputMsm::new(config.verifying_key(), self.public_inputs())
+    }
+}
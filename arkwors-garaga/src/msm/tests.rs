@@ -0,0 +1,68 @@
+mod public_input_msm {
+    use ark_ec::{CurveGroup, VariableBaseMSM};
+    use ark_ff::Field;
+    use ark_groth16::VerifyingKey;
+    use ark_std::{test_rng, UniformRand};
+    use assert_matches::assert_matches;
+
+    use crate::{
+        test_utils::random_verifying_key, GaragaCurve, GaragaError, Groth16VerifierConfiguration,
+        Groth16VerifierInputs, PublicInputMsm,
+    };
+
+    fn new_for<E: GaragaCurve>() {
+        let vk = random_verifying_key::<E>(4);
+        let mut public_inputs = (0..2)
+            .map(|_| E::ScalarField::rand(&mut test_rng()))
+            .collect::<Vec<_>>();
+        public_inputs.push(-E::ScalarField::from(1u8));
+        let shift = E::ScalarField::from(2u8).pow([128]);
+
+        let hint = PublicInputMsm::new(&vk, &public_inputs).unwrap();
+        for (i, input) in public_inputs.iter().enumerate() {
+            let low = E::ScalarField::from(hint.scalars_low()[i]);
+            let high = E::ScalarField::from(hint.scalars_high()[i]);
+            assert_eq!(&(low + high * shift), input);
+        }
+        assert_eq!(
+            *hint.q_high_shifted(),
+            (*hint.q_high() * shift).into_affine()
+        );
+        assert_eq!(
+            *hint.result(),
+            (E::G1::msm_unchecked(&vk.gamma_abc_g1[1..], &public_inputs) + vk.gamma_abc_g1[0])
+                .into_affine()
+        );
+
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+        let inputs = Groth16VerifierInputs::from_proof(Default::default(), public_inputs);
+        assert_eq!(inputs.public_input_msm(&config).unwrap(), hint);
+    }
+
+    #[test]
+    fn new() {
+        new_for::<ark_bn254::Bn254>();
+        new_for::<ark_bls12_381::Bls12_381>();
+    }
+
+    #[test]
+    fn new_rejects_key_without_ic() {
+        assert_matches!(
+            PublicInputMsm::new(&VerifyingKey::<ark_bn254::Bn254>::default(), &[]),
+            Err(GaragaError::NoIc)
+        );
+    }
+
+    #[test]
+    fn new_rejects_public_input_count_mismatch() {
+        let vk = random_verifying_key::<ark_bn254::Bn254>(3);
+
+        assert_matches!(
+            PublicInputMsm::new(&vk, &[ark_bn254::Fr::from(1u8)]),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+}
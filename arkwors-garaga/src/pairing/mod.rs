@@ -0,0 +1,414 @@
+//! The Miller loop, as run by Garaga's verifiers
+//!
+//! Garaga works with affine G2 points and line functions normalized by the `y`
+//! coordinate of the G1 point. Its Miller loop output thus differs from the
+//! arkworks one by a factor the final exponentiation gets rid of.
+
+#[cfg(test)]
+mod tests;
+
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr,
+};
+use ark_ff::{Field, Fp12, Fp12Config, Fp2, Fp2Config, Fp6, Fp6Config, One, PrimeField, Zero};
+use num_bigint::BigUint;
+use num_integer::Integer;
+
+use crate::{
+    check_not_at_infinity, check_point, GaragaCurve, GaragaError, InvalidPointReason, PointId,
+};
+
+pub(crate) type Fq<C> = <<<C as Fp12Config>::Fp6Config as Fp6Config>::Fp2Config as Fp2Config>::Fp;
+pub(crate) type Fq2<C> = Fp2<<<C as Fp12Config>::Fp6Config as Fp6Config>::Fp2Config>;
+
+/// How G2 is mapped onto the sextic twist of the curve
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Twist {
+    /// `(x, y) -> (x·w², y·w³)`, used by BN254
+    D,
+    /// `(x, y) -> (x/w², y/w³)`, used by BLS12-381
+    M,
+}
+
+/// The curve specific parts of Garaga's Miller loop
+///
+/// It is not nameable outside of the crate, which seals `GaragaCurve`.
+pub trait PairingCurve:
+    Pairing<
+    BaseField = Fq<Self::Fp12Config>,
+    G1Affine = Affine<Self::G1Config>,
+    G2Affine = Affine<Self::G2Config>,
+    TargetField = Fp12<Self::Fp12Config>,
+>
+{
+    type Fp12Config: Fp12Config;
+    type G1Config: SWCurveConfig<BaseField = Fq<Self::Fp12Config>, ScalarField = Self::ScalarField>;
+    type G2Config: SWCurveConfig<BaseField = Fq2<Self::Fp12Config>, ScalarField = Self::ScalarField>;
+
+    const TWIST: Twist;
+
+    /// Whether the loop runs over `-x` rather than `x`, in which case its result is conjugated
+    const LOOP_COUNTER_IS_NEGATIVE: bool;
+
+    /// Signed digits of the loop counter, most significant first, the leading one excluded
+    fn loop_digits() -> Vec<i8>;
+
+    /// Points added to `T` once the loop is over
+    fn final_points(q: &Affine<Self::G2Config>) -> Vec<Affine<Self::G2Config>>;
+
+    /// The `λ` for which `f · w = c^λ` proves that the final exponentiation of `f` is one
+    fn lambda() -> BigUint;
+}
+
+impl PairingCurve for ark_bn254::Bn254 {
+    type Fp12Config = ark_bn254::Fq12Config;
+    type G1Config = ark_bn254::g1::Config;
+    type G2Config = ark_bn254::g2::Config;
+
+    const TWIST: Twist = Twist::D;
+    const LOOP_COUNTER_IS_NEGATIVE: bool = false;
+
+    fn loop_digits() -> Vec<i8> {
+        let mut digits = <ark_bn254::Config as ark_ec::bn::BnConfig>::ATE_LOOP_COUNT.to_vec();
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        digits.pop();
+        digits.reverse();
+        digits
+    }
+
+    /// `π(Q)` and `-π²(Q)`, where `π` is the Frobenius endomorphism
+    fn final_points(q: &ark_bn254::G2Affine) -> Vec<ark_bn254::G2Affine> {
+        use ark_ec::bn::BnConfig;
+
+        let frobenius = |q: &ark_bn254::G2Affine| {
+            let mut q = *q;
+            q.x.frobenius_map_in_place(1);
+            q.x *= ark_bn254::Config::TWIST_MUL_BY_Q_X;
+            q.y.frobenius_map_in_place(1);
+            q.y *= ark_bn254::Config::TWIST_MUL_BY_Q_Y;
+            q
+        };
+        let q1 = frobenius(q);
+        let q2 = -frobenius(&q1);
+
+        vec![q1, q2]
+    }
+
+    /// `6x + 2 + p - p² + p³`
+    fn lambda() -> BigUint {
+        let x = BigUint::from(<ark_bn254::Config as ark_ec::bn::BnConfig>::X[0]);
+        let p: BigUint = ark_bn254::Fq::MODULUS.into();
+
+        6u8 * x + 2u8 + &p + p.pow(3) - p.pow(2)
+    }
+}
+
+impl PairingCurve for ark_bls12_381::Bls12_381 {
+    type Fp12Config = ark_bls12_381::Fq12Config;
+    type G1Config = ark_bls12_381::g1::Config;
+    type G2Config = ark_bls12_381::g2::Config;
+
+    const TWIST: Twist = Twist::M;
+    const LOOP_COUNTER_IS_NEGATIVE: bool =
+        <ark_bls12_381::Config as ark_ec::bls12::Bls12Config>::X_IS_NEGATIVE;
+
+    fn loop_digits() -> Vec<i8> {
+        let x = <ark_bls12_381::Config as ark_ec::bls12::Bls12Config>::X;
+        let mut digits = ark_ff::BitIteratorBE::without_leading_zeros(x)
+            .map(i8::from)
+            .collect::<Vec<_>>();
+        digits.remove(0);
+        digits
+    }
+
+    fn final_points(_q: &ark_bls12_381::G2Affine) -> Vec<ark_bls12_381::G2Affine> {
+        Vec::new()
+    }
+
+    /// `p - x`
+    fn lambda() -> BigUint {
+        let x = BigUint::from(<ark_bls12_381::Config as ark_ec::bls12::Bls12Config>::X[0]);
+        let p: BigUint = ark_bls12_381::Fq::MODULUS.into();
+
+        p + x
+    }
+}
+
+/// A line of the Miller loop, `y = r0·x - r1` in the coordinates of the twist G2 lives on
+pub struct G2Line<E: GaragaCurve> {
+    r0: Fq2<E::Fp12Config>,
+    r1: Fq2<E::Fp12Config>,
+}
+
+// Deriving these would require `E` to implement them
+impl<E: GaragaCurve> Clone for G2Line<E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E: GaragaCurve> Copy for G2Line<E> {}
+
+impl<E: GaragaCurve> PartialEq for G2Line<E> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r0 == other.r0 && self.r1 == other.r1
+    }
+}
+
+impl<E: GaragaCurve> Eq for G2Line<E> {}
+
+impl<E: GaragaCurve> std::fmt::Debug for G2Line<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("G2Line")
+            .field("r0", &self.r0)
+            .field("r1", &self.r1)
+            .finish()
+    }
+}
+
+impl<E: GaragaCurve> G2Line<E> {
+    /// The line of slope `lambda` going through `t`
+    fn new(lambda: Fq2<E::Fp12Config>, t: &E::G2Affine) -> Self {
+        Self {
+            r0: lambda,
+            r1: lambda * t.x - t.y,
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_coefficients(
+        r0: <E::G2Affine as AffineRepr>::BaseField,
+        r1: <E::G2Affine as AffineRepr>::BaseField,
+    ) -> Self {
+        Self { r0, r1 }
+    }
+
+    /// The slope of the line
+    pub fn r0(&self) -> &<E::G2Affine as AffineRepr>::BaseField {
+        &self.r0
+    }
+
+    /// The opposite of its value at `x = 0`
+    pub fn r1(&self) -> &<E::G2Affine as AffineRepr>::BaseField {
+        &self.r1
+    }
+
+    /// Evaluate the line at `p`, divided by `p.y` (and multiplied by `w³` for M twists)
+    pub(crate) fn evaluate(&self, p: &PreparedG1<E>) -> E::TargetField {
+        let mut slope = self.r0;
+        slope.mul_assign_by_basefield(&p.x_neg_over_y);
+        let mut constant = self.r1;
+        constant.mul_assign_by_basefield(&p.y_inv);
+
+        let zero = Fq2::<E::Fp12Config>::zero();
+        let one = Fq2::<E::Fp12Config>::one();
+        match E::TWIST {
+            // 1 + slope·w + constant·w³
+            Twist::D => Fp12::new(Fp6::new(one, zero, zero), Fp6::new(slope, constant, zero)),
+            // constant + slope·w² + w³
+            Twist::M => Fp12::new(Fp6::new(constant, slope, zero), Fp6::new(zero, one, zero)),
+        }
+    }
+}
+
+/// A G1 point in the form Garaga evaluates lines at
+pub(crate) struct PreparedG1<E: GaragaCurve> {
+    x_neg_over_y: E::BaseField,
+    y_inv: E::BaseField,
+}
+
+impl<E: GaragaCurve> PreparedG1<E> {
+    /// `None` for the point at infinity
+    pub(crate) fn new(p: &E::G1Affine) -> Option<Self> {
+        let (x, y) = p.xy()?;
+        let y_inv = y.inverse()?;
+
+        Some(Self {
+            x_neg_over_y: -*x * y_inv,
+            y_inv,
+        })
+    }
+}
+
+/// The slope of the tangent at `t`
+fn tangent_slope<E: GaragaCurve>(t: &E::G2Affine) -> Option<Fq2<E::Fp12Config>> {
+    Some(t.x.square() * Fq2::<E::Fp12Config>::from(3u8) * t.y.double().inverse()?)
+}
+
+/// `2t` and the tangent at `t`
+fn double_step<E: GaragaCurve>(t: &E::G2Affine) -> Option<(E::G2Affine, G2Line<E>)> {
+    let lambda = tangent_slope::<E>(t)?;
+    let x = lambda.square() - t.x.double();
+    let y = lambda * (t.x - x) - t.y;
+
+    Some((Affine::new_unchecked(x, y), G2Line::new(lambda, t)))
+}
+
+/// `t + q` and the line going through both
+fn add_step<E: GaragaCurve>(t: &E::G2Affine, q: &E::G2Affine) -> Option<(E::G2Affine, G2Line<E>)> {
+    let lambda = (q.y - t.y) * (q.x - t.x).inverse()?;
+    let x = lambda.square() - t.x - q.x;
+    let y = lambda * (t.x - x) - t.y;
+
+    Some((Affine::new_unchecked(x, y), G2Line::new(lambda, t)))
+}
+
+/// `2t + q`, computed as `(t + q) + t`, and the two lines going through `t`
+///
+/// It starts with the tangent at `t` when `t = q`, which happens on the first step.
+fn double_and_add_step<E: GaragaCurve>(
+    t: &E::G2Affine,
+    q: &E::G2Affine,
+) -> Option<(E::G2Affine, [G2Line<E>; 2])> {
+    let lambda1 = if t == q {
+        tangent_slope::<E>(t)?
+    } else {
+        (q.y - t.y) * (q.x - t.x).inverse()?
+    };
+    let x2 = lambda1.square() - t.x - q.x;
+    let lambda2 = -lambda1 - t.y.double() * (x2 - t.x).inverse()?;
+    let x4 = lambda2.square() - t.x - x2;
+    let y4 = lambda2 * (t.x - x4) - t.y;
+
+    Some((
+        Affine::new_unchecked(x4, y4),
+        [G2Line::new(lambda1, t), G2Line::new(lambda2, t)],
+    ))
+}
+
+/// Every line of the Miller loop of `q`, in the order the loop consumes them
+///
+/// A zero digit uses one line, a non zero one two, and each of the final points one.
+/// Returns `None` if the loop hits an exceptional case, which cannot happen for
+/// points of the prime order subgroup.
+pub(crate) fn lines<E: GaragaCurve>(q: &E::G2Affine) -> Option<Vec<G2Line<E>>> {
+    let mut lines = Vec::new();
+    let mut t = *q;
+    for digit in E::loop_digits() {
+        match digit {
+            0 => {
+                let (doubled, line) = double_step(&t)?;
+                t = doubled;
+                lines.push(line);
+            }
+            _ => {
+                let q = if digit > 0 { *q } else { -*q };
+                let (sum, step_lines) = double_and_add_step(&t, &q)?;
+                t = sum;
+                lines.extend(step_lines);
+            }
+        }
+    }
+    for point in E::final_points(q) {
+        let (sum, line) = add_step(&t, &point)?;
+        t = sum;
+        lines.push(line);
+    }
+
+    Some(lines)
+}
+
+/// Same as `lines`, making sure first that `q` is a point of the prime order subgroup
+/// other than the point at infinity, which has no lines
+pub(crate) fn checked_lines<E: GaragaCurve>(
+    q: &E::G2Affine,
+    point: PointId,
+) -> Result<Vec<G2Line<E>>, GaragaError> {
+    check_not_at_infinity(q, point)?;
+    check_point(q, point)?;
+
+    lines(q).ok_or(GaragaError::InvalidPoint {
+        point,
+        reason: InvalidPointReason::NotInSubgroup,
+    })
+}
+
+/// The number of lines each step of the loop uses, before the final points are added
+pub(crate) fn step_sizes<E: GaragaCurve>() -> impl Iterator<Item = usize> {
+    E::loop_digits()
+        .into_iter()
+        .map(|digit| if digit == 0 { 1 } else { 2 })
+}
+
+/// The product of the Miller loops of `pairs`, evaluated with their precomputed lines
+///
+/// Pairs involving the point at infinity contribute nothing, as they would to the pairing.
+pub(crate) fn multi_miller_loop<E: GaragaCurve>(
+    pairs: &[(E::G1Affine, &[G2Line<E>])],
+) -> E::TargetField {
+    let mut pairs = pairs
+        .iter()
+        .filter_map(|(p, lines)| Some((PreparedG1::<E>::new(p)?, lines.iter())))
+        .collect::<Vec<_>>();
+
+    let mut f = E::TargetField::one();
+    for step_size in step_sizes::<E>() {
+        f.square_in_place();
+        for (p, lines) in pairs.iter_mut() {
+            for line in lines.by_ref().take(step_size) {
+                f *= line.evaluate(p);
+            }
+        }
+    }
+    // What's left are the lines of the final points, one per point
+    let final_steps = pairs.iter().map(|(_, lines)| lines.len()).max();
+    for _ in 0..final_steps.unwrap_or(0) {
+        for (p, lines) in pairs.iter_mut() {
+            if let Some(line) = lines.next() {
+                f *= line.evaluate(p);
+            }
+        }
+    }
+    if E::LOOP_COUNTER_IS_NEGATIVE {
+        f.conjugate_in_place();
+    }
+
+    f
+}
+
+/// `base^exponent`
+fn pow<F: Field>(base: &F, exponent: &BigUint) -> F {
+    base.pow(exponent.to_u64_digits())
+}
+
+/// Find `c` and `w` such that `f · w = c^λ`, with `w` a root of unity of order coprime to `r`
+///
+/// Such a pair exists iff the final exponentiation of `f` is one. `w` is built from the
+/// components of `f` whose order shares a factor with `λ / r`, which are the only ones that
+/// can stop `f` from being a `λ`-th power, and then `λ` is invertible on the rest of the group.
+pub(crate) fn residue_witness<E: GaragaCurve>(
+    f: &E::TargetField,
+) -> Option<(E::TargetField, E::TargetField)> {
+    let p: BigUint = E::BaseField::MODULUS.into();
+    let r: BigUint = E::ScalarField::MODULUS.into();
+    let n = p.pow(12) - 1u8;
+    let lambda = E::lambda();
+    debug_assert!((&lambda % &r).is_zero());
+
+    // `s`: the largest divisor of `n` made of primes dividing `λ / r`
+    let mut s = BigUint::one();
+    let mut common = n.gcd(&(&lambda / &r));
+    while !common.is_one() {
+        s *= &common;
+        common = (&n / &s).gcd(&common);
+    }
+    let cofactor = &n / &s;
+
+    // `w` cancels the component of `f` of order dividing `s`
+    let idempotent = &cofactor * cofactor.modinv(&s)?;
+    let w = pow(&f.inverse()?, &idempotent);
+    let residue = *f * w;
+
+    // What's left must lie in the subgroup of order `n / (r·s)`, on which `λ` is invertible
+    let order = &cofactor / &r;
+    if !pow(&residue, &order).is_one() {
+        return None;
+    }
+    let c = pow(&residue, &(&lambda % &order).modinv(&order)?);
+
+    Some((c, w))
+}
@@ -0,0 +1,83 @@
+mod multi_miller_loop {
+    use ark_ec::{pairing::MillerLoopOutput, CurveGroup};
+    use ark_std::{test_rng, UniformRand};
+
+    use crate::{
+        pairing::{lines, multi_miller_loop},
+        GaragaCurve,
+    };
+
+    fn matches_arkworks_pairing<E: GaragaCurve>() {
+        let rng = &mut test_rng();
+        let g1 = (0..3)
+            .map(|_| E::G1::rand(rng).into_affine())
+            .collect::<Vec<_>>();
+        let g2 = (0..3)
+            .map(|_| E::G2::rand(rng).into_affine())
+            .collect::<Vec<_>>();
+
+        let lines = g2
+            .iter()
+            .map(|q| lines::<E>(q).unwrap())
+            .collect::<Vec<_>>();
+        let pairs = g1
+            .iter()
+            .zip(&lines)
+            .map(|(p, lines)| (*p, lines.as_slice()))
+            .collect::<Vec<_>>();
+        let f = multi_miller_loop::<E>(&pairs);
+
+        assert_eq!(
+            E::final_exponentiation(MillerLoopOutput(f)).unwrap(),
+            E::multi_pairing(g1, g2)
+        );
+    }
+
+    #[test]
+    fn bn254() {
+        matches_arkworks_pairing::<ark_bn254::Bn254>();
+    }
+
+    #[test]
+    fn bls12_381() {
+        matches_arkworks_pairing::<ark_bls12_381::Bls12_381>();
+    }
+}
+
+mod residue_witness {
+    use ark_ec::CurveGroup;
+    use ark_ff::{Field, One};
+    use ark_std::{test_rng, UniformRand};
+
+    use crate::{
+        pairing::{lines, multi_miller_loop, residue_witness},
+        GaragaCurve,
+    };
+
+    fn witness<E: GaragaCurve>() {
+        let rng = &mut test_rng();
+        let p = E::G1::rand(rng).into_affine();
+        let q = E::G2::rand(rng).into_affine();
+        let lines = lines::<E>(&q).unwrap();
+
+        // e(P, Q)·e(-P, Q) = 1
+        let f = multi_miller_loop::<E>(&[(p, &lines), (-p, &lines)]);
+        assert!(!f.is_one());
+        let (c, w) = residue_witness::<E>(&f).unwrap();
+        assert_eq!(c.pow(E::lambda().to_u64_digits()), f * w);
+
+        // e(P, Q)·e(P, Q) != 1
+        let f = multi_miller_loop::<E>(&[(p, &lines), (p, &lines)]);
+        assert_eq!(residue_witness::<E>(&f), None);
+    }
+
+    #[test]
+    fn bn254() {
+        witness::<ark_bn254::Bn254>();
+    }
+
+    #[test]
+    fn bls12_381() {
+        witness::<ark_bls12_381::Bls12_381>();
+    }
+}
@@ -0,0 +1,327 @@
+//! RISC Zero Groth16 receipts
+//!
+//! RISC Zero wraps the STARK proof of a zkVM execution into a BN254 Groth16 proof, its seal,
+//! whose five public inputs are the control root and the digest of the receipt claim, each
+//! split in two 128 bits halves, and the BN254 control id. The claim commits to the image ID of
+//! the guest program and to its journal.
+//!
+//! The verifying key and control ids are the ones of `risc0-zkvm` 2.3, whose verifier
+//! parameters digest, the first four bytes of which prefix on chain seals, is
+//! `bb001d444841d70e8bc0c7d034b349044bf3cf0117afb702b2f1e898b7dd13cc`.
+
+#[cfg(test)]
+mod tests;
+
+use std::str::FromStr;
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ec::AffineRepr;
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_groth16::{Proof, VerifyingKey};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    check_point, GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    InvalidPointReason, PointId,
+};
+
+/// Root of the Merkle tree of the recursion programs allowed to produce the proof
+pub const CONTROL_ROOT: [u8; 32] =
+    hex32("ce52bf56033842021af3cf6db8a50d1b7535c125a34f1a22c6fdcf002c5a1529");
+
+/// Control id of the identity recursion program, using Poseidon over the BN254 scalar field
+pub const BN254_CONTROL_ID: [u8; 32] =
+    hex32("c07a65145c3cb48b6101962ea607a4dd93c753bb26975cb47feb00d3666e4404");
+
+/// The size of a seal, without the four bytes selector on chain verifiers expect
+pub const SEAL_SIZE: usize = 256;
+
+// From risc0-groth16 2.0.3, itself taken from risc0-ethereum's `Groth16Verifier.sol`
+const ALPHA: [&str; 2] = [
+    "20491192805390485299153009773594534940189261866228447918068658471970481763042",
+    "9383485363053290200918347156157836566562967994039712273449902621266178545958",
+];
+const BETA: [&str; 4] = [
+    "4252822878758300859123897981450591353533073413197771768651442665752259397132",
+    "6375614351688725206403948262868962793625744043794305715222011528459656738731",
+    "21847035105528745403288232691147584728191162732299865338377159692350059136679",
+    "10505242626370262277552901082094356697409835680220590971873171140371331206856",
+];
+const GAMMA: [&str; 4] = [
+    "11559732032986387107991004021392285783925812861821192530917403151452391805634",
+    "10857046999023057135944570762232829481370756359578518086990519993285655852781",
+    "4082367875863433681332203403145435568316851327593401208105741076214120093531",
+    "8495653923123431417604973247489272438418190587263600148770280649306958101930",
+];
+const DELTA: [&str; 4] = [
+    "1668323501672964604911431804142266013250380587483576094566949227275849579036",
+    "12043754404802191763554326994664886008979042643626290185762540825416902247219",
+    "7710631539206257456743780535472368339139328733484942210876916214502466455394",
+    "13740680757317479711909903993315946540841369848973133181051452051592786724563",
+];
+const IC: [[&str; 2]; 6] = [
+    [
+        "8446592859352799428420270221449902464741693648963397251242447530457567083492",
+        "1064796367193003797175961162477173481551615790032213185848276823815288302804",
+    ],
+    [
+        "3179835575189816632597428042194253779818690147323192973511715175294048485951",
+        "20895841676865356752879376687052266198216014795822152491318012491767775979074",
+    ],
+    [
+        "5332723250224941161709478398807683311971555792614491788690328996478511465287",
+        "21199491073419440416471372042641226693637837098357067793586556692319371762571",
+    ],
+    [
+        "12457994489566736295787256452575216703923664299075106359829199968023158780583",
+        "19706766271952591897761291684837117091856807401404423804318744964752784280790",
+    ],
+    [
+        "19617808913178163826953378459323299110911217259216006187355745713323154132237",
+        "21663537384585072695701846972542344484111393047775983928357046779215877070466",
+    ],
+    [
+        "6834578911681792552110317589222010969491336870276623105249474534788043166867",
+        "15060583660288623605191393599883223885678013570733629274538391874953353488393",
+    ],
+];
+
+/// Parse a 64 digits hex string at compile time
+const fn hex32(value: &str) -> [u8; 32] {
+    const fn digit(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("not a lowercase hex digit"),
+        }
+    }
+
+    let bytes = value.as_bytes();
+    let mut result = [0; 32];
+    let mut i = 0;
+    while i < 32 {
+        result[i] = digit(bytes[2 * i]) << 4 | digit(bytes[2 * i + 1]);
+        i += 1;
+    }
+    result
+}
+
+fn fq(value: &str) -> Fq {
+    Fq::from_str(value).expect("the constants of the key are field elements")
+}
+
+/// A G2 point from the `[x1, x0, y1, y0]` coordinates Solidity verifiers use
+fn g2(coordinates: [&str; 4]) -> G2Affine {
+    let [x1, x0, y1, y0] = coordinates.map(fq);
+    G2Affine::new(Fq2::new(x0, x1), Fq2::new(y0, y1))
+}
+
+/// The verifying key of RISC Zero's Groth16 circuit
+pub fn verifying_key() -> VerifyingKey<Bn254> {
+    VerifyingKey {
+        alpha_g1: G1Affine::new(fq(ALPHA[0]), fq(ALPHA[1])),
+        beta_g2: g2(BETA),
+        gamma_g2: g2(GAMMA),
+        delta_g2: g2(DELTA),
+        gamma_abc_g1: IC
+            .iter()
+            .map(|[x, y]| G1Affine::new(fq(x), fq(y)))
+            .collect(),
+    }
+}
+
+fn sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+/// RISC Zero's tagged hash of a struct, made of the digests `down` and the words `data`
+fn tagged_struct(tag: &str, down: &[[u8; 32]], data: &[u32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(sha256(tag.as_bytes()));
+    for digest in down {
+        hasher.update(digest);
+    }
+    for word in data {
+        hasher.update(word.to_le_bytes());
+    }
+    hasher.update((down.len() as u16).to_le_bytes());
+
+    hasher.finalize().into()
+}
+
+/// RISC Zero's tagged hash of a list, folded from its last element
+fn tagged_list(tag: &str, list: &[[u8; 32]]) -> [u8; 32] {
+    list.iter().rev().fold([0; 32], |tail, head| {
+        tagged_struct(tag, &[*head, tail], &[])
+    })
+}
+
+/// The big endian bytes of `value`, `Fp2` elements being written `c1` first
+pub(crate) fn be_bytes<F: Field>(value: &F) -> Vec<u8> {
+    let mut elems = value.to_base_prime_field_elements().collect::<Vec<_>>();
+    elems.reverse();
+
+    elems
+        .iter()
+        .flat_map(|c| c.into_bigint().to_bytes_be())
+        .collect()
+}
+
+/// The hash of the big endian coordinates of a point
+fn hash_point<A: AffineRepr>(point: &A) -> [u8; 32] {
+    let (x, y) = point
+        .xy()
+        .expect("the points of the key are not at infinity");
+
+    sha256(&[be_bytes(x), be_bytes(y)].concat())
+}
+
+/// The digest of RISC Zero's verifier parameters, committing to `CONTROL_ROOT`,
+/// `BN254_CONTROL_ID` and the verifying key
+pub fn verifier_parameters_digest() -> [u8; 32] {
+    let vk = verifying_key();
+    let ic = vk.gamma_abc_g1.iter().map(hash_point).collect::<Vec<_>>();
+    let vk_digest = tagged_struct(
+        "risc0_groth16.VerifyingKey",
+        &[
+            hash_point(&vk.alpha_g1),
+            hash_point(&vk.beta_g2),
+            hash_point(&vk.gamma_g2),
+            hash_point(&vk.delta_g2),
+            tagged_list("risc0_groth16.VerifyingKey.IC", &ic),
+        ],
+        &[],
+    );
+
+    tagged_struct(
+        "risc0.Groth16ReceiptVerifierParameters",
+        &[CONTROL_ROOT, BN254_CONTROL_ID, vk_digest],
+        &[],
+    )
+}
+
+/// The digest of the claim of an execution of the guest `image_id` that halted with exit code 0
+/// after writing `journal`, as computed by `ReceiptClaim::ok(image_id, journal).digest()`
+///
+/// `image_id` holds the bytes of the `Digest`, that is the little endian bytes of its `[u32; 8]` words.
+pub fn claim_digest(image_id: &[u8; 32], journal: &[u8]) -> [u8; 32] {
+    let post_state = tagged_struct("risc0.SystemState", &[[0; 32]], &[0]);
+    // The journal and the digest of an empty list of assumptions
+    let output = tagged_struct("risc0.Output", &[sha256(journal), [0; 32]], &[]);
+
+    // The input, pre state, post state and output, then the system and user exit codes
+    tagged_struct(
+        "risc0.ReceiptClaim",
+        &[[0; 32], *image_id, post_state, output],
+        &[0, 0],
+    )
+}
+
+/// The scalar of the little endian `bytes`
+fn scalar(bytes: &[u8]) -> Fr {
+    Fr::from_le_bytes_mod_order(bytes)
+}
+
+/// The public inputs of the proof of the claim `claim_digest`:
+/// the halves of `CONTROL_ROOT`, the ones of `claim_digest`, then `BN254_CONTROL_ID`
+///
+/// Digests are split in their first and last sixteen bytes, read as little endian integers.
+pub fn public_inputs(claim_digest: &[u8; 32]) -> Vec<Fr> {
+    let (a0, a1) = CONTROL_ROOT.split_at(16);
+    let (c0, c1) = claim_digest.split_at(16);
+
+    vec![
+        scalar(a0),
+        scalar(a1),
+        scalar(c0),
+        scalar(c1),
+        scalar(&BN254_CONTROL_ID),
+    ]
+}
+
+/// Parse a big endian base field element of the seal
+fn seal_coordinate(bytes: &[u8], point: PointId) -> Result<Fq, GaragaError> {
+    let value = num_bigint::BigUint::from_bytes_be(bytes);
+
+    <Fq as PrimeField>::BigInt::try_from(value.clone())
+        .ok()
+        .and_then(Fq::from_bigint)
+        .ok_or_else(|| GaragaError::InvalidPoint {
+            point,
+            reason: InvalidPointReason::InvalidCoordinate(format!("{value:#x}")),
+        })
+}
+
+/// Decode the `SEAL_SIZE` bytes of a proof laid out as Solidity verifiers expect it:
+/// the big endian coordinates of `a`, `b` and `c`, those of `b` being written `c1` first
+pub(crate) fn decode_proof(seal: &[u8]) -> Result<Proof<Bn254>, GaragaError> {
+    let words = seal.chunks(32).collect::<Vec<_>>();
+
+    let a = G1Affine::new_unchecked(
+        seal_coordinate(words[0], PointId::A)?,
+        seal_coordinate(words[1], PointId::A)?,
+    );
+    let [x1, x0, y1, y0] =
+        [words[2], words[3], words[4], words[5]].map(|w| seal_coordinate(w, PointId::B));
+    let b = G2Affine::new_unchecked(Fq2::new(x0?, x1?), Fq2::new(y0?, y1?));
+    let c = G1Affine::new_unchecked(
+        seal_coordinate(words[6], PointId::C)?,
+        seal_coordinate(words[7], PointId::C)?,
+    );
+    check_point(&a, PointId::A)?;
+    check_point(&b, PointId::B)?;
+    check_point(&c, PointId::C)?;
+
+    Ok(Proof { a, b, c })
+}
+
+/// Decode a seal, see `decode_proof`
+///
+/// The four bytes selector on chain verifiers expect may prefix it,
+/// in which case it must match `verifier_parameters_digest`.
+pub fn proof_from_seal(seal: &[u8]) -> Result<Proof<Bn254>, GaragaError> {
+    let seal = match seal.len() {
+        SEAL_SIZE => seal,
+        len if len == SEAL_SIZE + 4 => {
+            let (selector, seal) = seal.split_at(4);
+            if selector != &verifier_parameters_digest()[..4] {
+                return Err(GaragaError::InvalidSeal(format!(
+                    "unknown selector `0x{}`",
+                    selector
+                        .iter()
+                        .map(|b| format!("{b:02x}"))
+                        .collect::<String>()
+                )));
+            }
+            seal
+        }
+        len => {
+            return Err(GaragaError::InvalidSeal(format!(
+                "expected {SEAL_SIZE} bytes, found {len}"
+            )))
+        }
+    };
+    decode_proof(seal)
+}
+
+impl Groth16VerifierConfiguration<Bn254> {
+    /// The configuration of RISC Zero's Groth16 circuit, see `verifying_key`
+    pub fn risc0() -> Self {
+        Self::from_verifying_key(verifying_key())
+    }
+}
+
+impl Groth16VerifierInputs<Bn254> {
+    /// The inputs of the Groth16 receipt of an execution of the guest `image_id` that halted with
+    /// exit code 0 after writing `journal`, see `proof_from_seal` and `public_inputs`
+    pub fn from_risc0(
+        seal: &[u8],
+        image_id: &[u8; 32],
+        journal: &[u8],
+    ) -> Result<Self, GaragaError> {
+        Ok(Self::from_proof(
+            proof_from_seal(seal)?,
+            public_inputs(&claim_digest(image_id, journal)),
+        ))
+    }
+}
@@ -0,0 +1,145 @@
+mod verifying_key {
+    use crate::{
+        risc0::{verifier_parameters_digest, verifying_key},
+        Groth16VerifierConfiguration,
+    };
+
+    #[test]
+    fn verifier_parameters_digest_matches_risc0() {
+        // `Groth16ReceiptVerifierParameters::default().digest()` of risc0-zkvm 2.3.2
+        let expected = "bb001d444841d70e8bc0c7d034b349044bf3cf0117afb702b2f1e898b7dd13cc";
+        let digest = verifier_parameters_digest()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>();
+
+        assert_eq!(digest, expected);
+    }
+
+    #[test]
+    fn configuration() {
+        let config = Groth16VerifierConfiguration::risc0();
+
+        assert_eq!(config.verifying_key(), &verifying_key());
+        assert_eq!(config.verifying_key().gamma_abc_g1.len(), 6);
+        config.validate_points().unwrap();
+        assert!(config.audit().passed());
+    }
+}
+
+mod public_inputs {
+    use ark_ff::{BigInteger, PrimeField};
+
+    use crate::risc0::{claim_digest, public_inputs, BN254_CONTROL_ID, CONTROL_ROOT};
+
+    fn hex(value: ark_bn254::Fr) -> String {
+        value
+            .into_bigint()
+            .to_bytes_be()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+            .trim_start_matches('0')
+            .to_string()
+    }
+
+    #[test]
+    fn split_digests() {
+        let mut digest = [0; 32];
+        digest[0] = 1;
+        digest[16] = 2;
+        digest[31] = 3;
+        let inputs = public_inputs(&digest);
+
+        // The halves of the control root read as little endian integers
+        assert_eq!(hex(inputs[0]), "1b0da5b86dcff31a0242380356bf52ce");
+        assert_eq!(hex(inputs[1]), "29155a2c00cffdc6221a4fa325c13575");
+        assert_eq!(inputs[2], ark_bn254::Fr::from(1u8));
+        assert_eq!(
+            inputs[3],
+            ark_bn254::Fr::from(2u8) + ark_bn254::Fr::from(3u128 << 120)
+        );
+        assert_eq!(
+            hex(inputs[4]),
+            "4446e66d300eb7fb45c9726bb53c793dda407a62e9601618bb43c5c14657ac0"
+        );
+        assert_eq!(CONTROL_ROOT[0], 0xce);
+        assert_eq!(BN254_CONTROL_ID[31], 0x04);
+    }
+
+    #[test]
+    fn claim_digest_commits_to_image_id_and_journal() {
+        let image_id = [7; 32];
+        let digest = claim_digest(&image_id, b"journal");
+
+        assert_ne!(digest, claim_digest(&[8; 32], b"journal"));
+        assert_ne!(digest, claim_digest(&image_id, b"other journal"));
+        assert_eq!(digest, claim_digest(&image_id, b"journal"));
+    }
+}
+
+mod proof_from_seal {
+    use ark_ec::AffineRepr;
+    use assert_matches::assert_matches;
+
+    use crate::{
+        risc0::{claim_digest, proof_from_seal, public_inputs, verifier_parameters_digest},
+        test_utils::{seal, valid_proof},
+        GaragaError, Groth16VerifierInputs, InvalidPointReason, PointId,
+    };
+
+    #[test]
+    fn with_and_without_selector() {
+        let (_, proof, _) = valid_proof::<ark_bn254::Bn254>(5);
+        let seal = seal(&proof);
+        assert_eq!(proof_from_seal(&seal).unwrap(), proof);
+
+        let prefixed = [&verifier_parameters_digest()[..4], &seal].concat();
+        assert_eq!(proof_from_seal(&prefixed).unwrap(), proof);
+
+        let inputs = Groth16VerifierInputs::from_risc0(&prefixed, &[1; 32], b"journal").unwrap();
+        assert_eq!(inputs.proof(), &proof);
+        assert_eq!(
+            inputs.public_inputs(),
+            &public_inputs(&claim_digest(&[1; 32], b"journal"))
+        );
+    }
+
+    #[test]
+    fn refuses_invalid_seals() {
+        let (_, proof, _) = valid_proof::<ark_bn254::Bn254>(5);
+        let seal = seal(&proof);
+
+        assert_matches!(
+            proof_from_seal(&seal[1..]),
+            Err(GaragaError::InvalidSeal(message)) if message == "expected 256 bytes, found 255"
+        );
+        assert_matches!(
+            proof_from_seal(&[&[0x12, 0x34, 0x56, 0x78], seal.as_slice()].concat()),
+            Err(GaragaError::InvalidSeal(message)) if message == "unknown selector `0x12345678`"
+        );
+
+        // `a` moved off the curve
+        let mut invalid = seal.clone();
+        invalid[63] ^= 1;
+        assert_matches!(
+            proof_from_seal(&invalid),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::A,
+                reason: InvalidPointReason::NotOnCurve
+            })
+        );
+
+        // The x coordinate of `c` out of range
+        let mut invalid = seal;
+        invalid[192..224].fill(0xff);
+        assert_matches!(
+            proof_from_seal(&invalid),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::C,
+                reason: InvalidPointReason::InvalidCoordinate(_)
+            })
+        );
+        assert!(!proof.c.is_zero());
+    }
+}
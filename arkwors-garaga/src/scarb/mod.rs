@@ -0,0 +1,293 @@
+//! Scaffolding of a Scarb package holding a Garaga Groth16 verifier contract
+
+#[cfg(test)]
+mod tests;
+
+use std::{fs, path::Path};
+
+use num_bigint::BigUint;
+
+use crate::{ElipticCurveId, GaragaCurve, GaragaError, Groth16VerifierConfiguration};
+
+/// The Garaga release the generated code targets
+pub const GARAGA_VERSION: &str = "v0.15.3";
+
+/// A Scarb package to generate from a `Groth16VerifierConfiguration`
+///
+/// ```text
+/// <output_dir>/
+///     Scarb.toml
+///     src/lib.cairo
+///     src/groth16_verifier.cairo
+///     src/groth16_verifier_constants.cairo
+///     tests/test_contract.cairo
+/// ```
+///
+/// The contract test verifies the proof whose calldata is in `tests/proof_calldata.txt`. That file
+/// is not written here, as the hints of the calldata are not computed by this crate: write it with
+/// `garaga calldata --format snforge`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScarbProject {
+    name: String,
+    garaga_version: String,
+    ecip_ops_class_hash: String,
+}
+
+impl ScarbProject {
+    /// Fails if `name` is not a valid Scarb package name, or if `ecip_ops_class_hash`,
+    /// the class hash of the Garaga contract the verifier delegates MSMs to, is not a felt
+    pub fn new(name: &str, ecip_ops_class_hash: &str) -> Result<Self, GaragaError> {
+        let mut chars = name.chars();
+        let is_valid = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !is_valid {
+            return Err(GaragaError::InvalidProjectName(name.to_string()));
+        }
+        if !is_felt(ecip_ops_class_hash) {
+            return Err(GaragaError::InvalidFieldElement(
+                ecip_ops_class_hash.to_string(),
+            ));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            garaga_version: GARAGA_VERSION.to_string(),
+            ecip_ops_class_hash: ecip_ops_class_hash.to_string(),
+        })
+    }
+
+    /// Depend on another Garaga release than `GARAGA_VERSION`
+    pub fn with_garaga_version(mut self, garaga_version: &str) -> Self {
+        self.garaga_version = garaga_version.to_string();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn garaga_version(&self) -> &str {
+        &self.garaga_version
+    }
+
+    pub fn ecip_ops_class_hash(&self) -> &str {
+        &self.ecip_ops_class_hash
+    }
+
+    /// Write the package for `config` into `output_dir`, creating it if needed
+    pub fn write<E: GaragaCurve>(
+        &self,
+        config: &Groth16VerifierConfiguration<E>,
+        output_dir: &Path,
+    ) -> Result<(), GaragaError> {
+        let curve = CurveNames::of(E::ELIPTIC_CURVE_ID);
+        let constants = config.cairo_constants()?;
+
+        fs::create_dir_all(output_dir.join("src"))?;
+        fs::create_dir_all(output_dir.join("tests"))?;
+        fs::write(output_dir.join("Scarb.toml"), self.scarb_toml())?;
+        fs::write(
+            output_dir.join("src/lib.cairo"),
+            "pub mod groth16_verifier;\npub mod groth16_verifier_constants;\n",
+        )?;
+        fs::write(
+            output_dir.join("src/groth16_verifier.cairo"),
+            self.verifier_contract(&curve),
+        )?;
+        fs::write(
+            output_dir.join("src/groth16_verifier_constants.cairo"),
+            constants,
+        )?;
+        fs::write(
+            output_dir.join("tests/test_contract.cairo"),
+            self.contract_test(&curve),
+        )?;
+
+        Ok(())
+    }
+
+    fn scarb_toml(&self) -> String {
+        format!(
+            r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2024_07"
+
+[dependencies]
+garaga = {{ git = "https://github.com/keep-starknet-strange/garaga.git", tag = "{garaga_version}" }}
+starknet = "2.9.2"
+
+[dev-dependencies]
+snforge_std = "0.35.1"
+assert_macros = "2.9.2"
+
+[cairo]
+sierra-replace-ids = false
+
+[[target.starknet-contract]]
+casm = true
+casm-add-pythonic-hints = true
+
+[scripts]
+test = "snforge test"
+"#,
+            name = self.name,
+            garaga_version = self.garaga_version,
+        )
+    }
+
+    fn verifier_contract(&self, curve: &CurveNames) -> String {
+        format!(
+            r#"use super::groth16_verifier_constants::{{N_PUBLIC_INPUTS, ic, precomputed_lines, vk}};
+
+#[starknet::interface]
+pub trait IGroth16Verifier{upper}<TContractState> {{
+    fn verify_groth16_proof_{lower}(
+        self: @TContractState, full_proof_with_hints: Span<felt252>,
+    ) -> Option<Span<u256>>;
+}}
+
+#[starknet::contract]
+mod Groth16Verifier{upper} {{
+    use garaga::definitions::{{G1G2Pair, G1Point}};
+    use garaga::ec_ops::{{G1PointTrait, ec_safe_add}};
+    use garaga::ec_ops_g2::G2PointTrait;
+    use garaga::groth16::multi_pairing_check_{lower}_3P_2F_with_extra_miller_loop_result;
+    use garaga::utils::calldata::deserialize_full_proof_with_hints_{lower};
+    use starknet::SyscallResultTrait;
+    use super::{{N_PUBLIC_INPUTS, ic, precomputed_lines, vk}};
+
+    const ECIP_OPS_CLASS_HASH: felt252 = {ecip_ops_class_hash};
+
+    #[storage]
+    struct Storage {{}}
+
+    #[abi(embed_v0)]
+    impl IGroth16Verifier{upper} of super::IGroth16Verifier{upper}<ContractState> {{
+        fn verify_groth16_proof_{lower}(
+            self: @ContractState, full_proof_with_hints: Span<felt252>,
+        ) -> Option<Span<u256>> {{
+            let fph = deserialize_full_proof_with_hints_{lower}(full_proof_with_hints);
+            let groth16_proof = fph.groth16_proof;
+            let mpcheck_hint = fph.mpcheck_hint;
+            let small_Q = fph.small_Q;
+            let msm_hint = fph.msm_hint;
+
+            groth16_proof.a.assert_on_curve({curve_index});
+            groth16_proof.b.assert_on_curve({curve_index});
+            groth16_proof.c.assert_on_curve({curve_index});
+
+            let ic = ic.span();
+
+            let vk_x: G1Point = match ic.len() {{
+                0 => panic!("Malformed VK"),
+                1 => *ic.at(0),
+                _ => {{
+                    let mut msm_calldata: Array<felt252> = msm_hint;
+                    Serde::serialize(@ic.slice(1, N_PUBLIC_INPUTS), ref msm_calldata);
+                    Serde::serialize(@groth16_proof.public_inputs, ref msm_calldata);
+                    msm_calldata.append({curve_index});
+
+                    let mut vk_x_serialized = starknet::syscalls::library_call_syscall(
+                        ECIP_OPS_CLASS_HASH.try_into().unwrap(),
+                        selector!("msm_g1"),
+                        msm_calldata.span(),
+                    )
+                        .unwrap_syscall();
+
+                    ec_safe_add(
+                        Serde::<G1Point>::deserialize(ref vk_x_serialized).unwrap(),
+                        *ic.at(0),
+                        {curve_index},
+                    )
+                }},
+            }};
+
+            let check = multi_pairing_check_{lower}_3P_2F_with_extra_miller_loop_result(
+                G1G2Pair {{ p: vk_x, q: vk.gamma_g2 }},
+                G1G2Pair {{ p: groth16_proof.c, q: vk.delta_g2 }},
+                G1G2Pair {{ p: groth16_proof.a.negate({curve_index}), q: groth16_proof.b }},
+                vk.alpha_beta_miller_loop_result,
+                precomputed_lines.span(),
+                mpcheck_hint,
+                small_Q,
+            );
+            if check {{
+                Option::Some(groth16_proof.public_inputs)
+            }} else {{
+                Option::None
+            }}
+        }}
+    }}
+}}
+"#,
+            upper = curve.upper,
+            lower = curve.lower,
+            curve_index = curve.index,
+            ecip_ops_class_hash = self.ecip_ops_class_hash,
+        )
+    }
+
+    fn contract_test(&self, curve: &CurveNames) -> String {
+        format!(
+            r#"use snforge_std::fs::{{FileTrait, read_txt}};
+use snforge_std::{{ContractClassTrait, DeclareResultTrait, declare}};
+use {name}::groth16_verifier::{{
+    IGroth16Verifier{upper}Dispatcher, IGroth16Verifier{upper}DispatcherTrait,
+}};
+
+#[test]
+fn test_verify_groth16_proof_{lower}() {{
+    let contract = declare("Groth16Verifier{upper}").unwrap().contract_class();
+    let (contract_address, _) = contract.deploy(@array![]).unwrap();
+    let dispatcher = IGroth16Verifier{upper}Dispatcher {{ contract_address }};
+
+    let file = FileTrait::new('tests/proof_calldata.txt');
+    let calldata = read_txt(@file);
+    let result = dispatcher.verify_groth16_proof_{lower}(calldata.span());
+    assert(result.is_some(), 'Proof is invalid');
+}}
+"#,
+            name = self.name,
+            upper = curve.upper,
+            lower = curve.lower,
+        )
+    }
+}
+
+/// Whether `value` is a `0x` prefixed hex string of a value lower than the Starknet prime `2^251 + 17·2^192 + 1`
+fn is_felt(value: &str) -> bool {
+    let prime = (BigUint::from(1u8) << 251) + (BigUint::from(17u8) << 192) + 1u8;
+
+    value
+        .strip_prefix("0x")
+        .and_then(|digits| BigUint::parse_bytes(digits.as_bytes(), 16))
+        .is_some_and(|value| value < prime)
+}
+
+/// How Garaga names a curve in its Cairo code
+struct CurveNames {
+    upper: &'static str,
+    lower: &'static str,
+    /// The curve index of Garaga's `ec_ops`
+    index: u8,
+}
+
+impl CurveNames {
+    fn of(eliptic_curve_id: ElipticCurveId) -> Self {
+        match eliptic_curve_id {
+            ElipticCurveId::Bn254 => Self {
+                upper: "BN254",
+                lower: "bn254",
+                index: 0,
+            },
+            ElipticCurveId::Bls12_381 => Self {
+                upper: "BLS12_381",
+                lower: "bls12_381",
+                index: 1,
+            },
+        }
+    }
+}
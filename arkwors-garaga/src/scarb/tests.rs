@@ -0,0 +1,90 @@
+mod scarb_project {
+    use std::fs;
+
+    use assert_matches::assert_matches;
+
+    use crate::{
+        scarb::{ScarbProject, GARAGA_VERSION},
+        test_utils::random_verifying_key,
+        GaragaError, Groth16VerifierConfiguration,
+    };
+
+    const CLASS_HASH: &str = "0x684d2756a4440c190a5fe54e367c0abe33aefa75084dec2fffc791b620c80e3";
+
+    #[test]
+    fn new_and_getters() {
+        let project = ScarbProject::new("my_verifier2", CLASS_HASH).unwrap();
+        assert_eq!(project.name(), "my_verifier2");
+        assert_eq!(project.garaga_version(), GARAGA_VERSION);
+        assert_eq!(project.ecip_ops_class_hash(), CLASS_HASH);
+
+        let project = project.with_garaga_version("v0.14.0");
+        assert_eq!(project.garaga_version(), "v0.14.0");
+    }
+
+    #[test]
+    fn new_rejects_invalid_arguments() {
+        for name in ["", "MyVerifier", "my-verifier", "2verifier"] {
+            assert_matches!(
+                ScarbProject::new(name, CLASS_HASH),
+                Err(GaragaError::InvalidProjectName(n)) if n == name
+            );
+        }
+        for class_hash in [
+            "684d",
+            "0xzz",
+            "0x800000000000011000000000000000000000000000000000000000000000001",
+        ] {
+            assert_matches!(
+                ScarbProject::new("verifier", class_hash),
+                Err(GaragaError::InvalidFieldElement(h)) if h == class_hash
+            );
+        }
+    }
+
+    #[test]
+    fn write() {
+        let output_dir = std::env::temp_dir().join(format!(
+            "arkworks-garaga-scarb-project-{}",
+            std::process::id()
+        ));
+        let config = Groth16VerifierConfiguration::from_verifying_key(random_verifying_key::<
+            ark_bls12_381::Bls12_381,
+        >(2));
+
+        ScarbProject::new("my_verifier", CLASS_HASH)
+            .unwrap()
+            .write(&config, &output_dir)
+            .unwrap();
+
+        let read = |path: &str| fs::read_to_string(output_dir.join(path)).unwrap();
+        let scarb_toml = read("Scarb.toml");
+        assert!(scarb_toml.starts_with("[package]\nname = \"my_verifier\"\n"));
+        assert!(scarb_toml.contains(&format!(
+            "garaga = {{ git = \"https://github.com/keep-starknet-strange/garaga.git\", tag = \"{GARAGA_VERSION}\" }}"
+        )));
+        assert_eq!(
+            read("src/lib.cairo"),
+            "pub mod groth16_verifier;\npub mod groth16_verifier_constants;\n"
+        );
+        let contract = read("src/groth16_verifier.cairo");
+        assert!(contract.contains("mod Groth16VerifierBLS12_381 {"));
+        assert!(contract.contains("fn verify_groth16_proof_bls12_381("));
+        assert!(contract.contains(&format!(
+            "const ECIP_OPS_CLASS_HASH: felt252 = {CLASS_HASH};"
+        )));
+        assert_eq!(
+            read("src/groth16_verifier_constants.cairo"),
+            config.cairo_constants().unwrap()
+        );
+        let contract_test = read("tests/test_contract.cairo");
+        assert!(contract_test.contains(
+            "use my_verifier::groth16_verifier::{\n    IGroth16VerifierBLS12_381Dispatcher,"
+        ));
+        assert!(contract_test.contains(
+            "let result = dispatcher.verify_groth16_proof_bls12_381(calldata.span());\n    assert(result.is_some(), 'Proof is invalid');"
+        ));
+
+        fs::remove_dir_all(output_dir).unwrap();
+    }
+}
@@ -1,8 +1,111 @@
-use ark_ec::{pairing::Pairing, AffineRepr};
-use ark_ff::Field;
+use ark_ec::{pairing::Pairing, short_weierstrass::SWFlags, AffineRepr, CurveGroup};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_serialize::CanonicalSerializeWithFlags;
 use num_bigint::BigUint;
-use serde::{Deserialize, Serialize};
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
 
+use crate::{
+    check_eliptic_curve_id, check_not_at_infinity, ElipticCurveId, GaragaCurve, GaragaError,
+    InvalidPointReason, PointId,
+};
+
+/// Deserialize an `ElipticCurveId`, rejecting it if it is not the curve of `E`
+pub(crate) fn deserialize_eliptic_curve_id<'de, D: Deserializer<'de>, E: GaragaCurve>(
+    deserializer: D,
+) -> Result<ElipticCurveId, D::Error> {
+    let eliptic_curve_id = ElipticCurveId::deserialize(deserializer)?;
+    check_eliptic_curve_id::<E>(eliptic_curve_id).map_err(D::Error::custom)?;
+
+    Ok(eliptic_curve_id)
+}
+
+/// Parse a `0x` prefixed hex string into a prime field element, rejecting values out of range
+pub(crate) fn field_from_hex<F: PrimeField>(value: &str) -> Option<F> {
+    let digits = value.strip_prefix("0x")?;
+    let bi = BigUint::parse_bytes(digits.as_bytes(), 16)?;
+
+    F::BigInt::try_from(bi).ok().and_then(F::from_bigint)
+}
+
+/// Write the base prime field coordinates of `value` as `0x` prefixed hex strings
+fn field_to_hex<F: Field, const N: usize>(value: &F) -> Result<[String; N], GaragaError> {
+    let coordinates = value
+        .to_base_prime_field_elements()
+        .map(|v| format!("{:#01x}", Into::<BigUint>::into(v)))
+        .collect::<Vec<_>>();
+
+    coordinates
+        .try_into()
+        .map_err(|coordinates: Vec<_>| GaragaError::WrongExtensionDegree {
+            expected: N,
+            found: coordinates.len(),
+        })
+}
+
+/// Parse the `0x` prefixed hex coordinates of an extension field element,
+/// building the error for an invalid coordinate with `invalid`
+fn extension_field_from_hex_with<F: Field>(
+    values: &[String],
+    invalid: impl Fn(&String) -> GaragaError,
+) -> Result<F, GaragaError> {
+    let elems = values
+        .iter()
+        .map(|v| field_from_hex(v).ok_or_else(|| invalid(v)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    F::from_base_prime_field_elems(&elems).ok_or(GaragaError::WrongExtensionDegree {
+        expected: F::extension_degree() as usize,
+        found: values.len(),
+    })
+}
+
+/// Parse the `0x` prefixed hex coordinates of an extension field element
+fn extension_field_from_hex<F: Field>(values: &[String], point: PointId) -> Result<F, GaragaError> {
+    extension_field_from_hex_with(values, |v| GaragaError::InvalidPoint {
+        point,
+        reason: InvalidPointReason::InvalidCoordinate(v.clone()),
+    })
+}
+
+/// Rebuild an affine point from its coordinates, making sure it lies in the prime order subgroup
+///
+/// `(0, 0)`, which is not on the curve, is Garaga's encoding of the point at infinity.
+///
+/// `AffineRepr` exposes no constructor, so we go through `from_random_bytes`, which recovers
+/// a point from its `x` coordinate, and then pick the right `y` among the two candidates.
+fn affine_from_xy<A: AffineRepr>(
+    x: A::BaseField,
+    y: A::BaseField,
+    point: PointId,
+) -> Result<A, GaragaError> {
+    let invalid_point = |reason| GaragaError::InvalidPoint { point, reason };
+
+    if x.is_zero() && y.is_zero() {
+        return Ok(A::zero());
+    }
+
+    let mut bytes = Vec::new();
+    x.serialize_with_flags(&mut bytes, SWFlags::YIsPositive)?;
+
+    let candidate =
+        A::from_random_bytes(&bytes).ok_or(invalid_point(InvalidPointReason::NotOnCurve))?;
+    let candidate = match candidate.xy() {
+        Some((_, candidate_y)) if *candidate_y == y => candidate,
+        Some((_, candidate_y)) if *candidate_y == -y => (-candidate.into_group()).into_affine(),
+        _ => return Err(invalid_point(InvalidPointReason::NotOnCurve)),
+    };
+
+    if !candidate
+        .mul_bigint(<A::ScalarField as PrimeField>::MODULUS)
+        .is_zero()
+    {
+        return Err(invalid_point(InvalidPointReason::NotInSubgroup));
+    }
+
+    Ok(candidate)
+}
+
+/// A G1 point, the point at infinity being written `(0x0, 0x0)` as Garaga does
 #[derive(Serialize, Deserialize)]
 pub(crate) struct G1Point {
     x: String,
@@ -10,58 +113,67 @@ pub(crate) struct G1Point {
 }
 
 impl G1Point {
-    pub fn from_ark<E: Pairing>(value: &E::G1Affine) -> Self {
+    pub fn from_ark<E: Pairing>(value: &E::G1Affine) -> Result<Self, GaragaError> {
         let (x, y) = match <E::G1Affine as AffineRepr>::xy(value) {
             Some(v) => v,
             None => {
-                return G1Point {
+                return Ok(G1Point {
                     x: "0x0".to_string(),
                     y: "0x0".to_string(),
-                }
+                })
             }
         };
 
-        let x: BigUint  = <<<E as ark_ec::pairing::Pairing>::G1Affine as ark_ec::AffineRepr>::BaseField as Field>::to_base_prime_field_elements(x).next().unwrap().into();
-        let y: BigUint  = <<<E as ark_ec::pairing::Pairing>::G1Affine as ark_ec::AffineRepr>::BaseField as Field>::to_base_prime_field_elements(y).next().unwrap().into();
+        let [x] = field_to_hex(x)?;
+        let [y] = field_to_hex(y)?;
 
-        Self {
-            x: format!("{:#01x}", x),
-            y: format!("{:#01x}", y),
-        }
+        Ok(Self { x, y })
+    }
+
+    pub fn to_ark<E: Pairing>(&self, point: PointId) -> Result<E::G1Affine, GaragaError> {
+        let x = extension_field_from_hex(std::slice::from_ref(&self.x), point)?;
+        let y = extension_field_from_hex(std::slice::from_ref(&self.y), point)?;
+
+        affine_from_xy(x, y, point)
     }
 }
 
+/// A G2 point, the point at infinity being written with all its coordinates set to `0x0`
 #[derive(Serialize, Deserialize)]
 pub(crate) struct G2Point {
     x: [String; 2],
     y: [String; 2],
 }
 impl G2Point {
-    pub fn from_ark<E: Pairing>(value: &E::G2Affine) -> Self {
+    pub fn from_ark<E: Pairing>(value: &E::G2Affine) -> Result<Self, GaragaError> {
         let (x, y) = match <E::G2Affine as AffineRepr>::xy(value) {
             Some(v) => v,
             None => {
-                return G2Point {
+                return Ok(G2Point {
                     x: ["0x0".to_string(), "0x0".to_string()],
                     y: ["0x0".to_string(), "0x0".to_string()],
-                }
+                })
             }
         };
 
-        let mut x = <<<E as ark_ec::pairing::Pairing>::G2Affine as ark_ec::AffineRepr>::BaseField as Field>::to_base_prime_field_elements(x);
-        let mut y = <<<E as ark_ec::pairing::Pairing>::G2Affine as ark_ec::AffineRepr>::BaseField as Field>::to_base_prime_field_elements(y);
-        let x_c0: BigUint = x.next().unwrap().into();
-        let x_c1: BigUint = x.next().unwrap().into();
-        let y_c0: BigUint = y.next().unwrap().into();
-        let y_c1: BigUint = y.next().unwrap().into();
+        Ok(Self {
+            x: field_to_hex(x)?,
+            y: field_to_hex(y)?,
+        })
+    }
 
-        Self {
-            x: [format!("{:#01x}", x_c0), format!("{:#01x}", x_c1)],
-            y: [format!("{:#01x}", y_c0), format!("{:#01x}", y_c1)],
-        }
+    pub fn to_ark<E: Pairing>(&self, point: PointId) -> Result<E::G2Affine, GaragaError> {
+        let x = extension_field_from_hex(&self.x, point)?;
+        let y = extension_field_from_hex(&self.y, point)?;
+
+        affine_from_xy(x, y, point)
     }
 }
 
+/// A verifying key
+///
+/// Its `ic` points may legitimately be the point at infinity, for instance for unused
+/// public inputs, in which case they are written with Garaga's encoding of it.
 #[derive(Serialize, Deserialize)]
 pub(crate) struct VerifyingKey {
     pub alpha_g1: G1Point,
@@ -71,22 +183,42 @@ pub(crate) struct VerifyingKey {
     pub ic: Vec<G1Point>,
 }
 
-impl<E: Pairing> From<&ark_groth16::VerifyingKey<E>> for VerifyingKey {
-    fn from(value: &ark_groth16::VerifyingKey<E>) -> Self {
-        Self {
-            alpha_g1: G1Point::from_ark::<E>(&value.alpha_g1),
-            beta_g2: G2Point::from_ark::<E>(&value.beta_g2),
-            gamma_g2: G2Point::from_ark::<E>(&value.gamma_g2),
-            delta_g2: G2Point::from_ark::<E>(&value.delta_g2),
+impl<E: Pairing> TryFrom<&ark_groth16::VerifyingKey<E>> for VerifyingKey {
+    type Error = GaragaError;
+
+    fn try_from(value: &ark_groth16::VerifyingKey<E>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            alpha_g1: G1Point::from_ark::<E>(&value.alpha_g1)?,
+            beta_g2: G2Point::from_ark::<E>(&value.beta_g2)?,
+            gamma_g2: G2Point::from_ark::<E>(&value.gamma_g2)?,
+            delta_g2: G2Point::from_ark::<E>(&value.delta_g2)?,
             ic: value
                 .gamma_abc_g1
                 .iter()
                 .map(|v| G1Point::from_ark::<E>(v))
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl VerifyingKey {
+    pub fn to_ark<E: Pairing>(&self) -> Result<ark_groth16::VerifyingKey<E>, GaragaError> {
+        Ok(ark_groth16::VerifyingKey {
+            alpha_g1: self.alpha_g1.to_ark::<E>(PointId::AlphaG1)?,
+            beta_g2: self.beta_g2.to_ark::<E>(PointId::BetaG2)?,
+            gamma_g2: self.gamma_g2.to_ark::<E>(PointId::GammaG2)?,
+            delta_g2: self.delta_g2.to_ark::<E>(PointId::DeltaG2)?,
+            gamma_abc_g1: self
+                .ic
+                .iter()
+                .enumerate()
+                .map(|(i, v)| v.to_ark::<E>(PointId::Ic(i)))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
+/// A proof, none of whose points can be the point at infinity
 #[derive(Serialize, Deserialize)]
 pub(crate) struct Proof {
     pub a: G1Point,
@@ -94,12 +226,119 @@ pub(crate) struct Proof {
     pub c: G1Point,
 }
 
-impl<E: Pairing> From<&ark_groth16::Proof<E>> for Proof {
-    fn from(value: &ark_groth16::Proof<E>) -> Self {
-        Self {
-            a: G1Point::from_ark::<E>(&value.a),
-            b: G2Point::from_ark::<E>(&value.b),
-            c: G1Point::from_ark::<E>(&value.c),
+impl<E: Pairing> TryFrom<&ark_groth16::Proof<E>> for Proof {
+    type Error = GaragaError;
+
+    fn try_from(value: &ark_groth16::Proof<E>) -> Result<Self, Self::Error> {
+        check_not_at_infinity(&value.a, PointId::A)?;
+        check_not_at_infinity(&value.b, PointId::B)?;
+        check_not_at_infinity(&value.c, PointId::C)?;
+
+        Ok(Self {
+            a: G1Point::from_ark::<E>(&value.a)?,
+            b: G2Point::from_ark::<E>(&value.b)?,
+            c: G1Point::from_ark::<E>(&value.c)?,
+        })
+    }
+}
+
+impl Proof {
+    pub fn to_ark<E: Pairing>(&self) -> Result<ark_groth16::Proof<E>, GaragaError> {
+        let proof = ark_groth16::Proof {
+            a: self.a.to_ark::<E>(PointId::A)?,
+            b: self.b.to_ark::<E>(PointId::B)?,
+            c: self.c.to_ark::<E>(PointId::C)?,
+        };
+        check_not_at_infinity(&proof.a, PointId::A)?;
+        check_not_at_infinity(&proof.b, PointId::B)?;
+        check_not_at_infinity(&proof.c, PointId::C)?;
+
+        Ok(proof)
+    }
+}
+
+/// Write public inputs as `0x` prefixed hex strings
+pub(crate) fn public_inputs_to_hex<F: PrimeField>(public_inputs: &[F]) -> Vec<String> {
+    public_inputs
+        .iter()
+        .map(|&v| format!("{:#01x}", Into::<BigUint>::into(v)))
+        .collect()
+}
+
+/// Build the typed configuration of `E` from its curve independent representation
+pub(crate) fn configuration_from_serde<E: GaragaCurve>(
+    eliptic_curve_id: ElipticCurveId,
+    verifying_key: &VerifyingKey,
+) -> Result<crate::Groth16VerifierConfiguration<E>, GaragaError> {
+    crate::Groth16VerifierConfiguration::new(eliptic_curve_id, verifying_key.to_ark::<E>()?)
+}
+
+/// Build the typed inputs of `E` from their curve independent representation
+pub(crate) fn inputs_from_serde<E: GaragaCurve>(
+    eliptic_curve_id: ElipticCurveId,
+    proof: &Proof,
+    public_inputs: &[String],
+) -> Result<crate::Groth16VerifierInputs<E>, GaragaError> {
+    let public_inputs = public_inputs
+        .iter()
+        .map(|v| field_from_hex(v).ok_or_else(|| GaragaError::InvalidFieldElement(v.clone())))
+        .collect::<Result<_, _>>()?;
+
+    crate::Groth16VerifierInputs::new(eliptic_curve_id, proof.to_ark::<E>()?, public_inputs)
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct G2Line {
+    r0: [String; 2],
+    r1: [String; 2],
+}
+
+impl<E: GaragaCurve> TryFrom<&crate::G2Line<E>> for G2Line {
+    type Error = GaragaError;
+
+    fn try_from(value: &crate::G2Line<E>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            r0: field_to_hex(value.r0())?,
+            r1: field_to_hex(value.r1())?,
+        })
+    }
+}
+
+impl G2Line {
+    pub fn to_ark<E: GaragaCurve>(&self) -> Result<crate::G2Line<E>, GaragaError> {
+        let invalid = |v: &String| GaragaError::InvalidFieldElement(v.clone());
+
+        Ok(crate::G2Line::from_coefficients(
+            extension_field_from_hex_with(&self.r0, invalid)?,
+            extension_field_from_hex_with(&self.r1, invalid)?,
+        ))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(crate) struct E12([String; 12]);
+
+impl<E: GaragaCurve> TryFrom<&crate::GtElement<E>> for E12 {
+    type Error = GaragaError;
+
+    fn try_from(value: &crate::GtElement<E>) -> Result<Self, Self::Error> {
+        let mut coefficients: [String; 12] = Default::default();
+        for (hex, c) in coefficients.iter_mut().zip(value.coefficients()) {
+            [*hex] = field_to_hex(&c)?;
         }
+
+        Ok(Self(coefficients))
+    }
+}
+
+impl E12 {
+    pub fn to_ark<E: GaragaCurve>(&self) -> Result<crate::GtElement<E>, GaragaError> {
+        let mut coefficients = [E::BaseField::zero(); 12];
+        for (c, hex) in coefficients.iter_mut().zip(&self.0) {
+            *c =
+                field_from_hex(hex).ok_or_else(|| GaragaError::InvalidFieldElement(hex.clone()))?;
+        }
+
+        Ok(crate::GtElement::from_coefficients(coefficients))
     }
 }
@@ -0,0 +1,306 @@
+//! The JSON files of snarkjs: `verification_key.json`, `proof.json` and `public.json`
+//!
+//! snarkjs writes field elements as decimal strings and points as Jacobian `[x, y, z]`
+//! coordinates, `Fp2` elements being `[c0, c1]` pairs. The types of this module mirror those
+//! files, and are meant to be read and written with a JSON library such as `serde_json`.
+//!
+//! It is the snarkjs dialect of the crate, next to the Garaga one, so that the same arkworks
+//! proof can be checked with `snarkjs groth16 verify` or the Solidity verifiers of snarkjs.
+
+#[cfg(test)]
+mod tests;
+
+use ark_ec::{
+    short_weierstrass::{Affine, Projective, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{Field, One, PrimeField, Zero};
+use ark_groth16::{Proof, VerifyingKey};
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    check_point, check_public_input_count, ElipticCurveId, GaragaCurve, GaragaError,
+    Groth16VerifierConfiguration, Groth16VerifierInputs, InvalidPointReason, PointId,
+};
+
+/// A G1 point, as Jacobian `[x, y, z]` coordinates
+pub type SnarkjsG1Point = [String; 3];
+
+/// A G2 point, as Jacobian `[x, y, z]` coordinates, each of them a `[c0, c1]` pair
+pub type SnarkjsG2Point = [[String; 2]; 3];
+
+/// An element of the degree 12 extension field, as `[c0, c1]` nested down to the base field
+pub type SnarkjsFp12 = [[[String; 2]; 3]; 2];
+
+/// The content of a snarkjs `verification_key.json`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnarkjsVerifyingKey {
+    pub protocol: String,
+    pub curve: String,
+    pub n_public: usize,
+    #[serde(rename = "vk_alpha_1")]
+    pub vk_alpha_1: SnarkjsG1Point,
+    #[serde(rename = "vk_beta_2")]
+    pub vk_beta_2: SnarkjsG2Point,
+    #[serde(rename = "vk_gamma_2")]
+    pub vk_gamma_2: SnarkjsG2Point,
+    #[serde(rename = "vk_delta_2")]
+    pub vk_delta_2: SnarkjsG2Point,
+    /// `e(alpha_g1, beta_g2)`, which snarkjs writes but doesn't need to verify proofs
+    #[serde(
+        rename = "vk_alphabeta_12",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub vk_alphabeta_12: Option<SnarkjsFp12>,
+    #[serde(rename = "IC")]
+    pub ic: Vec<SnarkjsG1Point>,
+}
+
+/// The content of a snarkjs `proof.json`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnarkjsProof {
+    pub pi_a: SnarkjsG1Point,
+    pub pi_b: SnarkjsG2Point,
+    pub pi_c: SnarkjsG1Point,
+    pub protocol: String,
+    pub curve: String,
+}
+
+/// The content of a snarkjs `public.json`, the public inputs as decimal strings
+pub type SnarkjsPublicInputs = Vec<String>;
+
+/// The `ElipticCurveId` of a snarkjs curve name, which snarkjs compares case insensitively
+fn eliptic_curve_id(curve: &str) -> Result<ElipticCurveId, GaragaError> {
+    match curve.to_lowercase().as_str() {
+        "bn128" | "bn254" | "altbn128" => Ok(ElipticCurveId::Bn254),
+        "bls12381" => Ok(ElipticCurveId::Bls12_381),
+        _ => Err(GaragaError::InvalidElipticCurveId(curve.to_string())),
+    }
+}
+
+/// The name snarkjs gives to a curve
+fn curve_name(eliptic_curve_id: ElipticCurveId) -> String {
+    match eliptic_curve_id {
+        ElipticCurveId::Bn254 => "bn128".to_string(),
+        ElipticCurveId::Bls12_381 => "bls12381".to_string(),
+    }
+}
+
+/// Make sure a snarkjs file holds a Groth16 artifact over the curve of `E`
+fn check_header<E: GaragaCurve>(protocol: &str, curve: &str) -> Result<(), GaragaError> {
+    if protocol != "groth16" {
+        return Err(GaragaError::UnsupportedProtocol(protocol.to_string()));
+    }
+    crate::check_eliptic_curve_id::<E>(eliptic_curve_id(curve)?)
+}
+
+/// Parse a decimal string into a prime field element, rejecting values out of range
+fn field_from_decimal<F: PrimeField>(value: &str) -> Option<F> {
+    let bi = BigUint::parse_bytes(value.as_bytes(), 10)?;
+
+    F::BigInt::try_from(bi).ok().and_then(F::from_bigint)
+}
+
+/// Parse the decimal coordinates of an extension field element
+fn extension_field_from_decimal<F: Field>(
+    values: &[String],
+    point: PointId,
+) -> Result<F, GaragaError> {
+    let elems = values
+        .iter()
+        .map(|v| {
+            field_from_decimal(v).ok_or_else(|| GaragaError::InvalidPoint {
+                point,
+                reason: InvalidPointReason::InvalidCoordinate(v.clone()),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    F::from_base_prime_field_elems(&elems).ok_or(GaragaError::WrongExtensionDegree {
+        expected: F::extension_degree() as usize,
+        found: values.len(),
+    })
+}
+
+/// Rebuild a point from its Jacobian coordinates, making sure it lies in the prime order subgroup
+fn point_from_jacobian<P: SWCurveConfig>(
+    coordinates: [&[String]; 3],
+    point: PointId,
+) -> Result<Affine<P>, GaragaError> {
+    let [x, y, z] = coordinates.map(|c| extension_field_from_decimal::<P::BaseField>(c, point));
+    let value = Projective::<P>::new_unchecked(x?, y?, z?).into_affine();
+    check_point(&value, point)?;
+
+    Ok(value)
+}
+
+fn g1_from_snarkjs<P: SWCurveConfig>(
+    value: &SnarkjsG1Point,
+    point: PointId,
+) -> Result<Affine<P>, GaragaError> {
+    point_from_jacobian(value.each_ref().map(std::slice::from_ref), point)
+}
+
+fn g2_from_snarkjs<P: SWCurveConfig>(
+    value: &SnarkjsG2Point,
+    point: PointId,
+) -> Result<Affine<P>, GaragaError> {
+    point_from_jacobian(value.each_ref().map(|c| c.as_slice()), point)
+}
+
+/// The decimal base prime field coordinates of `value`
+fn decimal<F: Field>(value: &F) -> Vec<String> {
+    value
+        .to_base_prime_field_elements()
+        .map(|v| Into::<BigUint>::into(v).to_string())
+        .collect()
+}
+
+/// The Jacobian coordinates of `value`, `z` being one, or zero for the point at infinity
+fn jacobian_coordinates<P: SWCurveConfig>(value: &Affine<P>) -> [Vec<String>; 3] {
+    match value.xy() {
+        Some((x, y)) => [decimal(x), decimal(y), decimal(&P::BaseField::one())],
+        None => [
+            decimal(&P::BaseField::zero()),
+            decimal(&P::BaseField::one()),
+            decimal(&P::BaseField::zero()),
+        ],
+    }
+}
+
+fn g1_to_snarkjs<P: SWCurveConfig>(value: &Affine<P>) -> SnarkjsG1Point {
+    jacobian_coordinates(value).map(|mut c| c.remove(0))
+}
+
+fn g2_to_snarkjs<P: SWCurveConfig>(value: &Affine<P>) -> SnarkjsG2Point {
+    jacobian_coordinates(value).map(|c| [c[0].clone(), c[1].clone()])
+}
+
+fn fp12_to_snarkjs<F: Field>(value: &F) -> SnarkjsFp12 {
+    let coordinates = decimal(value);
+
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            let k = 6 * i + 2 * j;
+            [coordinates[k].clone(), coordinates[k + 1].clone()]
+        })
+    })
+}
+
+impl SnarkjsVerifyingKey {
+    /// The snarkjs `verification_key.json` of `vk`
+    pub fn from_ark<E: GaragaCurve>(vk: &VerifyingKey<E>) -> Self {
+        Self {
+            protocol: "groth16".to_string(),
+            curve: curve_name(E::ELIPTIC_CURVE_ID),
+            n_public: vk.gamma_abc_g1.len().saturating_sub(1),
+            vk_alpha_1: g1_to_snarkjs(&vk.alpha_g1),
+            vk_beta_2: g2_to_snarkjs(&vk.beta_g2),
+            vk_gamma_2: g2_to_snarkjs(&vk.gamma_g2),
+            vk_delta_2: g2_to_snarkjs(&vk.delta_g2),
+            vk_alphabeta_12: Some(fp12_to_snarkjs(&E::pairing(vk.alpha_g1, vk.beta_g2).0)),
+            ic: vk.gamma_abc_g1.iter().map(g1_to_snarkjs).collect(),
+        }
+    }
+
+    /// Fails if the key is not a Groth16 key over the curve of `E`, if `nPublic` doesn't match
+    /// the number of `IC` points, or if one of the points is invalid
+    pub fn to_ark<E: GaragaCurve>(&self) -> Result<VerifyingKey<E>, GaragaError> {
+        check_header::<E>(&self.protocol, &self.curve)?;
+
+        let vk = VerifyingKey {
+            alpha_g1: g1_from_snarkjs(&self.vk_alpha_1, PointId::AlphaG1)?,
+            beta_g2: g2_from_snarkjs(&self.vk_beta_2, PointId::BetaG2)?,
+            gamma_g2: g2_from_snarkjs(&self.vk_gamma_2, PointId::GammaG2)?,
+            delta_g2: g2_from_snarkjs(&self.vk_delta_2, PointId::DeltaG2)?,
+            gamma_abc_g1: self
+                .ic
+                .iter()
+                .enumerate()
+                .map(|(i, ic)| g1_from_snarkjs(ic, PointId::Ic(i)))
+                .collect::<Result<_, _>>()?,
+        };
+        check_public_input_count(&vk, self.n_public)?;
+
+        Ok(vk)
+    }
+}
+
+impl SnarkjsProof {
+    /// The snarkjs `proof.json` of `proof`
+    pub fn from_ark<E: GaragaCurve>(proof: &Proof<E>) -> Self {
+        Self {
+            pi_a: g1_to_snarkjs(&proof.a),
+            pi_b: g2_to_snarkjs(&proof.b),
+            pi_c: g1_to_snarkjs(&proof.c),
+            protocol: "groth16".to_string(),
+            curve: curve_name(E::ELIPTIC_CURVE_ID),
+        }
+    }
+
+    /// Fails if the proof is not a Groth16 proof over the curve of `E`,
+    /// or if one of its points is invalid
+    pub fn to_ark<E: GaragaCurve>(&self) -> Result<Proof<E>, GaragaError> {
+        check_header::<E>(&self.protocol, &self.curve)?;
+
+        Ok(Proof {
+            a: g1_from_snarkjs(&self.pi_a, PointId::A)?,
+            b: g2_from_snarkjs(&self.pi_b, PointId::B)?,
+            c: g1_from_snarkjs(&self.pi_c, PointId::C)?,
+        })
+    }
+}
+
+/// Parse the decimal public inputs of a snarkjs `public.json`
+pub fn public_inputs_from_snarkjs<F: PrimeField>(
+    public_inputs: &[String],
+) -> Result<Vec<F>, GaragaError> {
+    public_inputs
+        .iter()
+        .map(|v| field_from_decimal(v).ok_or_else(|| GaragaError::InvalidFieldElement(v.clone())))
+        .collect()
+}
+
+/// Write public inputs as the decimal strings of a snarkjs `public.json`
+pub fn public_inputs_to_snarkjs<F: PrimeField>(public_inputs: &[F]) -> SnarkjsPublicInputs {
+    public_inputs
+        .iter()
+        .map(|v| Into::<BigUint>::into(*v).to_string())
+        .collect()
+}
+
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Same as `from_verifying_key`, for a snarkjs `verification_key.json`
+    pub fn from_snarkjs(verifying_key: &SnarkjsVerifyingKey) -> Result<Self, GaragaError> {
+        Ok(Self::from_verifying_key(verifying_key.to_ark()?))
+    }
+
+    /// The snarkjs `verification_key.json` of the verifying key
+    pub fn to_snarkjs(&self) -> SnarkjsVerifyingKey {
+        SnarkjsVerifyingKey::from_ark(self.verifying_key())
+    }
+}
+
+impl<E: GaragaCurve> Groth16VerifierInputs<E> {
+    /// Same as `from_proof`, for a snarkjs `proof.json` and `public.json`
+    pub fn from_snarkjs(
+        proof: &SnarkjsProof,
+        public_inputs: &[String],
+    ) -> Result<Self, GaragaError> {
+        Ok(Self::from_proof(
+            proof.to_ark()?,
+            public_inputs_from_snarkjs(public_inputs)?,
+        ))
+    }
+
+    /// The snarkjs `proof.json` and `public.json` of the proof and its public inputs
+    pub fn to_snarkjs(&self) -> (SnarkjsProof, SnarkjsPublicInputs) {
+        (
+            SnarkjsProof::from_ark(self.proof()),
+            public_inputs_to_snarkjs(self.public_inputs()),
+        )
+    }
+}
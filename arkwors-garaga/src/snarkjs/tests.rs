@@ -0,0 +1,237 @@
+// A key, proof and public inputs written by snarkjs, taken from the test data of risc0-groth16 2.0.3
+const VERIFYING_KEY: &str = include_str!("../../fixtures/snarkjs/verification_key.json");
+const PROOF: &str = include_str!("../../fixtures/snarkjs/proof.json");
+const PUBLIC_INPUTS: &str = include_str!("../../fixtures/snarkjs/public.json");
+
+/// The Jacobian coordinates `(x·z², y·z³, z)` of an affine point, as decimal strings
+fn jacobian<F: ark_ff::Field>(x: F, y: F, z: F) -> [Vec<String>; 3] {
+    let decimal = |value: F| {
+        value
+            .to_base_prime_field_elements()
+            .map(|c| Into::<num_bigint::BigUint>::into(c).to_string())
+            .collect::<Vec<_>>()
+    };
+
+    [
+        decimal(x * z.square()),
+        decimal(y * z.square() * z),
+        decimal(z),
+    ]
+}
+
+mod snarkjs_verifying_key {
+    use ark_ec::AffineRepr;
+    use ark_groth16::VerifyingKey;
+    use ark_std::{test_rng, UniformRand};
+    use assert_matches::assert_matches;
+
+    use super::{jacobian, VERIFYING_KEY};
+    use crate::{
+        snarkjs::SnarkjsVerifyingKey, ElipticCurveId, GaragaError, Groth16VerifierConfiguration,
+        InvalidPointReason, PointId,
+    };
+
+    fn g1(point: &ark_bls12_381::G1Affine, z: ark_bls12_381::Fq) -> [String; 3] {
+        let (x, y) = point.xy().unwrap();
+        jacobian(*x, *y, z).map(|c| c[0].clone())
+    }
+
+    fn g2(point: &ark_bls12_381::G2Affine, z: ark_bls12_381::Fq2) -> [[String; 2]; 3] {
+        let (x, y) = point.xy().unwrap();
+        jacobian(*x, *y, z).map(|c| [c[0].clone(), c[1].clone()])
+    }
+
+    #[test]
+    fn to_ark() {
+        let vk: SnarkjsVerifyingKey = serde_json::from_str(VERIFYING_KEY).unwrap();
+        let ark_vk = vk.to_ark::<ark_bn254::Bn254>().unwrap();
+
+        assert_eq!(ark_vk.gamma_abc_g1.len(), 2);
+        assert_eq!(
+            ark_vk.alpha_g1.x().unwrap().to_string(),
+            "1294134766316609703328581643861691998063901679593305122518960283123018706388"
+        );
+        assert_eq!(
+            Groth16VerifierConfiguration::from_snarkjs(&vk).unwrap(),
+            Groth16VerifierConfiguration::from_verifying_key(ark_vk)
+        );
+    }
+
+    #[test]
+    fn to_ark_from_jacobian_coordinates() {
+        let mut rng = test_rng();
+        let vk = VerifyingKey::<ark_bls12_381::Bls12_381> {
+            alpha_g1: UniformRand::rand(&mut rng),
+            beta_g2: UniformRand::rand(&mut rng),
+            gamma_g2: UniformRand::rand(&mut rng),
+            delta_g2: UniformRand::rand(&mut rng),
+            gamma_abc_g1: vec![UniformRand::rand(&mut rng), UniformRand::rand(&mut rng)],
+        };
+        let mut z1 = || ark_bls12_381::Fq::rand(&mut rng);
+        let snarkjs_vk = SnarkjsVerifyingKey {
+            protocol: "groth16".to_string(),
+            curve: "bls12381".to_string(),
+            n_public: 1,
+            vk_alpha_1: g1(&vk.alpha_g1, z1()),
+            vk_beta_2: g2(&vk.beta_g2, ark_bls12_381::Fq2::new(z1(), z1())),
+            vk_gamma_2: g2(&vk.gamma_g2, ark_bls12_381::Fq2::new(z1(), z1())),
+            vk_delta_2: g2(&vk.delta_g2, ark_bls12_381::Fq2::new(z1(), z1())),
+            vk_alphabeta_12: None,
+            ic: vk.gamma_abc_g1.iter().map(|ic| g1(ic, z1())).collect(),
+        };
+
+        assert_eq!(snarkjs_vk.to_ark::<ark_bls12_381::Bls12_381>().unwrap(), vk);
+    }
+
+    #[test]
+    fn from_ark() {
+        let vk: SnarkjsVerifyingKey = serde_json::from_str(VERIFYING_KEY).unwrap();
+        let ark_vk = vk.to_ark::<ark_bn254::Bn254>().unwrap();
+
+        // Including the `vk_alphabeta_12` snarkjs computed
+        assert_eq!(SnarkjsVerifyingKey::from_ark(&ark_vk), vk);
+        assert_eq!(
+            Groth16VerifierConfiguration::from_verifying_key(ark_vk).to_snarkjs(),
+            vk
+        );
+    }
+
+    #[test]
+    fn serialization() {
+        let mut rng = test_rng();
+        let vk = VerifyingKey::<ark_bls12_381::Bls12_381> {
+            alpha_g1: UniformRand::rand(&mut rng),
+            beta_g2: UniformRand::rand(&mut rng),
+            gamma_g2: UniformRand::rand(&mut rng),
+            delta_g2: UniformRand::rand(&mut rng),
+            gamma_abc_g1: vec![UniformRand::rand(&mut rng), ark_bls12_381::G1Affine::zero()],
+        };
+
+        let json = serde_json::to_value(SnarkjsVerifyingKey::from_ark(&vk)).unwrap();
+        assert_eq!(json["protocol"], "groth16");
+        assert_eq!(json["curve"], "bls12381");
+        assert_eq!(json["nPublic"], 1);
+        assert_eq!(json["vk_alpha_1"][2], "1");
+        assert_eq!(json["vk_beta_2"][2], serde_json::json!(["1", "0"]));
+        assert_eq!(json["IC"][1], serde_json::json!(["0", "1", "0"]));
+
+        let snarkjs_vk: SnarkjsVerifyingKey = serde_json::from_value(json).unwrap();
+        assert_eq!(snarkjs_vk.to_ark::<ark_bls12_381::Bls12_381>().unwrap(), vk);
+    }
+
+    #[test]
+    fn to_ark_rejects_invalid_keys() {
+        let vk: SnarkjsVerifyingKey = serde_json::from_str(VERIFYING_KEY).unwrap();
+
+        assert_matches!(
+            vk.to_ark::<ark_bls12_381::Bls12_381>(),
+            Err(GaragaError::CurveMismatch {
+                expected: ElipticCurveId::Bls12_381,
+                found: ElipticCurveId::Bn254
+            })
+        );
+        assert_matches!(
+            SnarkjsVerifyingKey {
+                curve: "secp256k1".to_string(),
+                ..vk.clone()
+            }
+            .to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::InvalidElipticCurveId(curve)) if curve == "secp256k1"
+        );
+        assert_matches!(
+            SnarkjsVerifyingKey {
+                protocol: "plonk".to_string(),
+                ..vk.clone()
+            }
+            .to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::UnsupportedProtocol(protocol)) if protocol == "plonk"
+        );
+        assert_matches!(
+            SnarkjsVerifyingKey {
+                n_public: 2,
+                ..vk.clone()
+            }
+            .to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+
+        let mut off_curve = vk.clone();
+        off_curve.ic[1][1] = "1".to_string();
+        assert_matches!(
+            off_curve.to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::Ic(1),
+                reason: InvalidPointReason::NotOnCurve
+            })
+        );
+
+        let mut invalid_coordinate = vk;
+        invalid_coordinate.vk_beta_2[0][1] = "0x1".to_string();
+        assert_matches!(
+            invalid_coordinate.to_ark::<ark_bn254::Bn254>(),
+            Err(GaragaError::InvalidPoint {
+                point: PointId::BetaG2,
+                reason: InvalidPointReason::InvalidCoordinate(c)
+            }) if c == "0x1"
+        );
+    }
+}
+
+mod snarkjs_proof {
+    use assert_matches::assert_matches;
+
+    use super::{PROOF, PUBLIC_INPUTS, VERIFYING_KEY};
+    use crate::{
+        snarkjs::{
+            public_inputs_from_snarkjs, public_inputs_to_snarkjs, SnarkjsProof, SnarkjsVerifyingKey,
+        },
+        GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+
+    #[test]
+    fn from_snarkjs_verifies() {
+        let vk: SnarkjsVerifyingKey = serde_json::from_str(VERIFYING_KEY).unwrap();
+        let proof: SnarkjsProof = serde_json::from_str(PROOF).unwrap();
+        let public_inputs: Vec<String> = serde_json::from_str(PUBLIC_INPUTS).unwrap();
+
+        let config = Groth16VerifierConfiguration::<ark_bn254::Bn254>::from_snarkjs(&vk).unwrap();
+        let inputs = Groth16VerifierInputs::from_snarkjs(&proof, &public_inputs).unwrap();
+        assert_eq!(inputs.proof(), &proof.to_ark().unwrap());
+        assert_eq!(inputs.public_inputs(), &vec![ark_bn254::Fr::from(33u8)]);
+        inputs.verify(&config).unwrap();
+    }
+
+    #[test]
+    fn to_snarkjs() {
+        let proof: SnarkjsProof = serde_json::from_str(PROOF).unwrap();
+        let public_inputs: Vec<String> = serde_json::from_str(PUBLIC_INPUTS).unwrap();
+        let inputs =
+            Groth16VerifierInputs::<ark_bn254::Bn254>::from_snarkjs(&proof, &public_inputs)
+                .unwrap();
+
+        assert_eq!(inputs.to_snarkjs(), (proof.clone(), public_inputs.clone()));
+        assert_eq!(
+            serde_json::to_value(&proof).unwrap(),
+            serde_json::from_str::<serde_json::Value>(PROOF).unwrap()
+        );
+        assert_eq!(
+            public_inputs_to_snarkjs(inputs.public_inputs()),
+            public_inputs
+        );
+    }
+
+    #[test]
+    fn public_inputs_from_snarkjs_rejects_out_of_range_values() {
+        // The scalar field modulus itself
+        let modulus =
+            "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+        assert_matches!(
+            public_inputs_from_snarkjs::<ark_bn254::Fr>(&[modulus.to_string()]),
+            Err(GaragaError::InvalidFieldElement(v)) if v == modulus
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! SP1 Groth16 proofs
+//!
+//! SP1 wraps its STARK proofs into BN254 Groth16 proofs whose two public inputs are the hash of
+//! the verifying key of the program and the digest of its public values, as in SP1 4.
+//! On chain proofs are prefixed by a four bytes selector, the first bytes of the SHA-256 hash of
+//! the gnark verifying key of the circuit.
+//!
+//! That key is pinned by each SP1 release, which ships it in `sp1-verifier` as
+//! `GROTH16_VK_BYTES`, the bytes gnark's `VerifyingKey.WriteTo` writes.
+//! `Groth16VerifierConfiguration::sp1` reads it.
+
+#[cfg(test)]
+mod tests;
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::PrimeField;
+use ark_groth16::Proof;
+use num_bigint::BigUint;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    check_public_input_count, gnark,
+    risc0::{decode_proof, SEAL_SIZE},
+    GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+};
+
+/// The size of a proof, its selector included
+pub const PROOF_SIZE: usize = 4 + SEAL_SIZE;
+
+/// The selector of the proofs of the circuit whose gnark verifying key is `groth16_vk`
+pub fn selector(groth16_vk: &[u8]) -> [u8; 4] {
+    let digest = Sha256::digest(groth16_vk);
+    [digest[0], digest[1], digest[2], digest[3]]
+}
+
+/// Parse the `0x` prefixed hex hash of the verifying key of a program, as `vk.bytes32()` returns it
+pub fn vkey_hash(value: &str) -> Result<Fr, GaragaError> {
+    value
+        .strip_prefix("0x")
+        .filter(|digits| digits.len() == 64)
+        .and_then(|digits| BigUint::parse_bytes(digits.as_bytes(), 16))
+        .and_then(|value| <Fr as PrimeField>::BigInt::try_from(value).ok())
+        .and_then(Fr::from_bigint)
+        .ok_or_else(|| GaragaError::InvalidFieldElement(value.to_string()))
+}
+
+/// The digest of the public values of a proof: their SHA-256 hash, its three most significant
+/// bits cleared so that it fits the scalar field
+pub fn public_values_digest(public_values: &[u8]) -> Fr {
+    let mut digest: [u8; 32] = Sha256::digest(public_values).into();
+    digest[0] &= 0x1f;
+
+    Fr::from_be_bytes_mod_order(&digest)
+}
+
+/// The public inputs of a proof of the program `vkey_hash`, having committed to `public_values`
+pub fn public_inputs(vkey_hash: Fr, public_values: &[u8]) -> Vec<Fr> {
+    vec![vkey_hash, public_values_digest(public_values)]
+}
+
+/// Decode a proof, made of its selector followed by its points laid out as in a RISC Zero seal
+///
+/// The selector must match the one of `groth16_vk`.
+pub fn proof_from_bytes(proof: &[u8], groth16_vk: &[u8]) -> Result<Proof<Bn254>, GaragaError> {
+    if proof.len() != PROOF_SIZE {
+        return Err(GaragaError::InvalidSeal(format!(
+            "expected {PROOF_SIZE} bytes, found {}",
+            proof.len()
+        )));
+    }
+    let (proof_selector, seal) = proof.split_at(4);
+    if proof_selector != selector(groth16_vk) {
+        return Err(GaragaError::InvalidSeal(format!(
+            "unknown selector `0x{}`",
+            proof_selector
+                .iter()
+                .map(|b| format!("{b:02x}"))
+                .collect::<String>()
+        )));
+    }
+
+    decode_proof(seal)
+}
+
+impl Groth16VerifierConfiguration<Bn254> {
+    /// The configuration of the SP1 circuit whose gnark verifying key is `groth16_vk`,
+    /// see the `sp1` module
+    pub fn sp1(groth16_vk: &[u8]) -> Result<Self, GaragaError> {
+        let vk = gnark::read_verifying_key(groth16_vk)?;
+        check_public_input_count(&vk, 2)?;
+
+        Ok(Self::from_verifying_key(vk))
+    }
+}
+
+impl Groth16VerifierInputs<Bn254> {
+    /// The inputs of an SP1 proof of the program `vkey_hash` having committed to
+    /// `public_values`, see `proof_from_bytes` and `public_inputs`
+    pub fn from_sp1(
+        proof: &[u8],
+        vkey_hash: &str,
+        public_values: &[u8],
+        groth16_vk: &[u8],
+    ) -> Result<Self, GaragaError> {
+        Ok(Self::from_proof(
+            proof_from_bytes(proof, groth16_vk)?,
+            public_inputs(self::vkey_hash(vkey_hash)?, public_values),
+        ))
+    }
+}
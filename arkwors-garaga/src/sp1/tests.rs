@@ -0,0 +1,118 @@
+use ark_groth16::VerifyingKey;
+
+use crate::{
+    sp1::{public_inputs, selector, vkey_hash},
+    test_utils::{seal, valid_proof_for, verifying_key_bytes},
+};
+
+const VKEY_HASH: &str = "0x00a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f";
+
+/// A key of the SP1 circuit's shape, its gnark bytes and a proof of `public_values`
+fn sp1_proof(public_values: &[u8]) -> (VerifyingKey<ark_bn254::Bn254>, Vec<u8>, Vec<u8>) {
+    let inputs = public_inputs(vkey_hash(VKEY_HASH).unwrap(), public_values);
+    let (vk, proof) = valid_proof_for::<ark_bn254::Bn254>(&inputs);
+    let groth16_vk = verifying_key_bytes(&vk, true);
+    let proof = [selector(&groth16_vk).to_vec(), seal(&proof)].concat();
+
+    (vk, groth16_vk, proof)
+}
+
+mod public_inputs {
+    use ark_ff::{BigInteger, PrimeField};
+    use assert_matches::assert_matches;
+
+    use super::VKEY_HASH;
+    use crate::{
+        sp1::{public_values_digest, vkey_hash},
+        GaragaError,
+    };
+
+    #[test]
+    fn vkey_hash_is_big_endian() {
+        let value = vkey_hash(VKEY_HASH).unwrap();
+
+        assert_eq!(
+            value.into_bigint().to_string(),
+            num_bigint::BigUint::parse_bytes(&VKEY_HASH.as_bytes()[2..], 16)
+                .unwrap()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn refuses_invalid_vkey_hashes() {
+        for invalid in [
+            &VKEY_HASH[2..],
+            &VKEY_HASH[..65],
+            "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "0xzz00000000000000000000000000000000000000000000000000000000000000",
+        ] {
+            assert_matches!(
+                vkey_hash(invalid),
+                Err(GaragaError::InvalidFieldElement(value)) if value == invalid
+            );
+        }
+    }
+
+    #[test]
+    fn public_values_digest_is_masked() {
+        // sha256("") = e3b0c442...
+        let digest = public_values_digest(b"");
+        let bytes = digest.into_bigint().to_bytes_be();
+
+        assert_eq!(bytes[0], 0xe3 & 0x1f);
+        assert_eq!(bytes[1..4], [0xb0, 0xc4, 0x42]);
+    }
+}
+
+mod from_sp1 {
+    use assert_matches::assert_matches;
+
+    use super::{sp1_proof, VKEY_HASH};
+    use crate::{
+        sp1::{proof_from_bytes, public_inputs, vkey_hash},
+        test_utils::{valid_proof, verifying_key_bytes},
+        GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+
+    #[test]
+    fn verifies() {
+        let (vk, groth16_vk, proof) = sp1_proof(b"public values");
+        let config = Groth16VerifierConfiguration::sp1(&groth16_vk).unwrap();
+        let inputs =
+            Groth16VerifierInputs::from_sp1(&proof, VKEY_HASH, b"public values", &groth16_vk)
+                .unwrap();
+
+        assert_eq!(config.verifying_key(), &vk);
+        assert_eq!(
+            inputs.public_inputs(),
+            &public_inputs(vkey_hash(VKEY_HASH).unwrap(), b"public values")
+        );
+        inputs.verify(&config).unwrap();
+    }
+
+    #[test]
+    fn refuses_other_circuits() {
+        let (vk, groth16_vk, proof) = sp1_proof(b"public values");
+        // The same key written uncompressed, whose selector differs
+        let other_vk = verifying_key_bytes(&vk, false);
+
+        assert_matches!(
+            proof_from_bytes(&proof[1..], &groth16_vk),
+            Err(GaragaError::InvalidSeal(message)) if message == "expected 260 bytes, found 259"
+        );
+        assert_matches!(
+            proof_from_bytes(&proof, &other_vk),
+            Err(GaragaError::InvalidSeal(message)) if message.starts_with("unknown selector")
+        );
+        // Not a key of a circuit with two public inputs
+        let (vk, _, _) = valid_proof::<ark_bn254::Bn254>(3);
+        assert_matches!(
+            Groth16VerifierConfiguration::sp1(&verifying_key_bytes(&vk, false)),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 3,
+                found: 2
+            })
+        );
+    }
+}
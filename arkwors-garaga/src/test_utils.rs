@@ -0,0 +1,189 @@
+//! Fixtures shared by the unit tests
+
+use ark_ec::{
+    pairing::Pairing,
+    short_weierstrass::{Affine, SWCurveConfig},
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{Field, One, PrimeField};
+use ark_groth16::{Proof, VerifyingKey};
+use ark_std::{test_rng, UniformRand};
+use num_bigint::BigUint;
+
+use crate::{risc0::be_bytes, ElipticCurveId, GaragaCurve};
+
+/// A verifying key, a proof and public inputs satisfying the Groth16 equation,
+/// built from known discrete logarithms rather than from a circuit
+pub(crate) fn valid_proof<E: Pairing>(
+    n_public_inputs: usize,
+) -> (VerifyingKey<E>, Proof<E>, Vec<E::ScalarField>) {
+    let rng = &mut test_rng();
+    let public_inputs = (0..n_public_inputs)
+        .map(|_| E::ScalarField::rand(rng))
+        .collect::<Vec<_>>();
+    let (vk, proof) = valid_proof_for::<E>(&public_inputs);
+
+    (vk, proof, public_inputs)
+}
+
+/// Same as `valid_proof`, for the given public inputs
+pub(crate) fn valid_proof_for<E: Pairing>(
+    public_inputs: &[E::ScalarField],
+) -> (VerifyingKey<E>, Proof<E>) {
+    let rng = &mut test_rng();
+    let g1 = E::G1Affine::generator();
+    let g2 = E::G2Affine::generator();
+    let mut scalar = || E::ScalarField::rand(rng);
+
+    let (alpha, beta, gamma, delta) = (scalar(), scalar(), scalar(), scalar());
+    let ic = (0..=public_inputs.len())
+        .map(|_| scalar())
+        .collect::<Vec<_>>();
+    let (a, b) = (scalar(), scalar());
+
+    // a·b = α·β + vk_x·γ + c·δ
+    let vk_x = ic[0]
+        + ic[1..]
+            .iter()
+            .zip(public_inputs)
+            .map(|(ic, input)| *ic * input)
+            .sum::<E::ScalarField>();
+    let c = (a * b - alpha * beta - vk_x * gamma) * delta.inverse().unwrap();
+
+    let vk = VerifyingKey {
+        alpha_g1: (g1 * alpha).into_affine(),
+        beta_g2: (g2 * beta).into_affine(),
+        gamma_g2: (g2 * gamma).into_affine(),
+        delta_g2: (g2 * delta).into_affine(),
+        gamma_abc_g1: ic.iter().map(|ic| (g1 * ic).into_affine()).collect(),
+    };
+    let proof = Proof {
+        a: (g1 * a).into_affine(),
+        b: (g2 * b).into_affine(),
+        c: (g1 * c).into_affine(),
+    };
+
+    (vk, proof)
+}
+
+/// A verifying key made of random points, with `n_ic` `ic` points
+pub(crate) fn random_verifying_key<E: Pairing>(n_ic: usize) -> VerifyingKey<E> {
+    let mut rng = test_rng();
+    VerifyingKey {
+        alpha_g1: E::G1Affine::rand(&mut rng),
+        beta_g2: E::G2Affine::rand(&mut rng),
+        gamma_g2: E::G2Affine::rand(&mut rng),
+        delta_g2: E::G2Affine::rand(&mut rng),
+        gamma_abc_g1: (0..n_ic).map(|_| E::G1Affine::rand(&mut rng)).collect(),
+    }
+}
+
+/// A point of the curve outside of its prime order subgroup, the curve must have a cofactor
+pub(crate) fn outside_subgroup<P: SWCurveConfig>() -> Affine<P> {
+    let mut x = P::BaseField::one();
+    loop {
+        if let Some(p) = Affine::<P>::get_point_from_x_unchecked(x, true) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                return p;
+            }
+        }
+        x += P::BaseField::one();
+    }
+}
+
+/// The big endian bytes of a field element, `A1` first for `Fp2` elements, as gnark writes them
+pub(crate) fn field_bytes<F: Field>(value: &F) -> Vec<u8> {
+    let size = <F::BasePrimeField as PrimeField>::MODULUS_BIT_SIZE.div_ceil(8) as usize;
+    let mut elems = value.to_base_prime_field_elements().collect::<Vec<_>>();
+    elems.reverse();
+
+    elems
+        .into_iter()
+        .flat_map(|c| {
+            let bytes = Into::<BigUint>::into(c).to_bytes_be();
+            let mut padded = vec![0; size - bytes.len()];
+            padded.extend(bytes);
+            padded
+        })
+        .collect()
+}
+
+/// A point as gnark-crypto encodes it, following its `Bytes` and `RawBytes`
+pub(crate) fn point_bytes<P: ark_ec::short_weierstrass::SWCurveConfig>(
+    value: &Affine<P>,
+    eliptic_curve_id: ElipticCurveId,
+    compressed: bool,
+) -> Vec<u8> {
+    let (infinity, smallest, largest) = match eliptic_curve_id {
+        ElipticCurveId::Bn254 => (0b0100_0000, 0b1000_0000, 0b1100_0000),
+        ElipticCurveId::Bls12_381 => (0b1100_0000, 0b1000_0000, 0b1010_0000),
+    };
+    let size = field_bytes(&P::BaseField::ZERO).len();
+
+    match (value.xy(), compressed) {
+        (None, true) => {
+            let mut bytes = vec![0; size];
+            bytes[0] = infinity;
+            bytes
+        }
+        (None, false) => {
+            let mut bytes = vec![0; 2 * size];
+            if eliptic_curve_id == ElipticCurveId::Bls12_381 {
+                bytes[0] = 0b0100_0000;
+            }
+            bytes
+        }
+        (Some((x, y)), true) => {
+            let mut bytes = field_bytes(x);
+            bytes[0] |= if *y > -*y { largest } else { smallest };
+            bytes
+        }
+        (Some((x, y)), false) => [field_bytes(x), field_bytes(y)].concat(),
+    }
+}
+
+/// The bytes `VerifyingKey.WriteTo` (compressed) or `WriteRawTo` would write for `vk`,
+/// reusing `alpha_g1` for the G1 `β` and `δ` verifiers don't need
+pub(crate) fn verifying_key_bytes<E: GaragaCurve>(
+    vk: &VerifyingKey<E>,
+    compressed: bool,
+) -> Vec<u8> {
+    let id = E::ELIPTIC_CURVE_ID;
+    let mut bytes = [
+        point_bytes(&vk.alpha_g1, id, compressed),
+        point_bytes(&vk.alpha_g1, id, compressed),
+        point_bytes(&vk.beta_g2, id, compressed),
+        point_bytes(&vk.gamma_g2, id, compressed),
+        point_bytes(&vk.alpha_g1, id, compressed),
+        point_bytes(&vk.delta_g2, id, compressed),
+    ]
+    .concat();
+    bytes.extend((vk.gamma_abc_g1.len() as u32).to_be_bytes());
+    for ic in &vk.gamma_abc_g1 {
+        bytes.extend(point_bytes(ic, id, compressed));
+    }
+    // No public input committed to, no commitment key
+    bytes.extend(0u32.to_be_bytes());
+    bytes.extend(0u32.to_be_bytes());
+    bytes
+}
+
+/// The big endian coordinates of the points of `proof`, those of `b` written `c1` first,
+/// as RISC Zero seals and SP1 proofs lay them out
+pub(crate) fn seal(proof: &Proof<ark_bn254::Bn254>) -> Vec<u8> {
+    let (a, b, c) = (
+        proof.a.xy().unwrap(),
+        proof.b.xy().unwrap(),
+        proof.c.xy().unwrap(),
+    );
+
+    [
+        be_bytes(a.0),
+        be_bytes(a.1),
+        be_bytes(b.0),
+        be_bytes(b.1),
+        be_bytes(c.0),
+        be_bytes(c.1),
+    ]
+    .concat()
+}
@@ -4,28 +4,63 @@ mod tests;
 use ark_ec::pairing::Pairing;
 use ark_groth16::VerifyingKey;
 
-use crate::ElipticCurveId;
+use crate::{
+    check_eliptic_curve_id, check_point,
+    pairing::{checked_lines, multi_miller_loop, step_sizes},
+    ElipticCurveId, G2Line, GaragaCurve, GaragaError, GtElement, PointId,
+};
 
 /// Everything needed to initialize a verfier contract for a specific groth16 circuit
+///
+/// The `ic` points of a verifying key may be the point at infinity, for instance for
+/// public inputs the circuit doesn't use. Such points are written `(0, 0)`, which is how
+/// Garaga encodes the point at infinity.
 #[derive(Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "E: GaragaCurve"))
+)]
 pub struct Groth16VerifierConfiguration<E: Pairing> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde::deserialize_eliptic_curve_id::<_, E>")
+    )]
     eliptic_curve_id: ElipticCurveId,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "impl_serde::serialize_verifying_key")
+        serde(
+            serialize_with = "impl_serde::serialize_verifying_key",
+            deserialize_with = "impl_serde::deserialize_verifying_key"
+        )
     )]
     verifying_key: VerifyingKey<E>,
 }
 
-impl<E: Pairing> Groth16VerifierConfiguration<E> {
-    pub fn new(eliptic_curve_id: ElipticCurveId, verifying_key: VerifyingKey<E>) -> Self {
-        Self {
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Fails if `eliptic_curve_id` is not the curve of `E`
+    pub fn new(
+        eliptic_curve_id: ElipticCurveId,
+        verifying_key: VerifyingKey<E>,
+    ) -> Result<Self, GaragaError> {
+        check_eliptic_curve_id::<E>(eliptic_curve_id)?;
+
+        Ok(Self {
             eliptic_curve_id,
             verifying_key,
+        })
+    }
+
+    /// Same as `new`, with the eliptic curve id inferred from `E`
+    pub fn from_verifying_key(verifying_key: VerifyingKey<E>) -> Self {
+        Self {
+            eliptic_curve_id: E::ELIPTIC_CURVE_ID,
+            verifying_key,
         }
     }
+}
 
+impl<E: Pairing> Groth16VerifierConfiguration<E> {
     pub fn eliptic_curve_id(&self) -> ElipticCurveId {
         self.eliptic_curve_id
     }
@@ -35,19 +70,152 @@ impl<E: Pairing> Groth16VerifierConfiguration<E> {
     }
 }
 
+impl<E: GaragaCurve> Groth16VerifierConfiguration<E> {
+    /// Make sure every point of the verifying key is on the curve and in its prime order subgroup
+    ///
+    /// The error names the first point that is not.
+    pub fn validate_points(&self) -> Result<(), GaragaError> {
+        let vk = &self.verifying_key;
+        check_point(&vk.alpha_g1, PointId::AlphaG1)?;
+        check_point(&vk.beta_g2, PointId::BetaG2)?;
+        check_point(&vk.gamma_g2, PointId::GammaG2)?;
+        check_point(&vk.delta_g2, PointId::DeltaG2)?;
+        for (i, ic) in vk.gamma_abc_g1.iter().enumerate() {
+            check_point(ic, PointId::Ic(i))?;
+        }
+
+        Ok(())
+    }
+
+    /// The Miller loop lines of `gamma_g2` and `delta_g2`
+    ///
+    /// Fails if one of them is not a point of the prime order subgroup.
+    pub fn precomputed_lines(&self) -> Result<PrecomputedLines<E>, GaragaError> {
+        Ok(PrecomputedLines {
+            gamma_g2: checked_lines(&self.verifying_key.gamma_g2, PointId::GammaG2)?,
+            delta_g2: checked_lines(&self.verifying_key.delta_g2, PointId::DeltaG2)?,
+        })
+    }
+
+    /// The Miller loop result of `alpha_g1` and `beta_g2`, computed the way Garaga's verifiers do
+    ///
+    /// It is the counterpart of the `alpha_g1_beta_g2` of `ark_groth16::prepare_verifying_key`,
+    /// without the final exponentiation, which Garaga's verifiers never run.
+    pub fn alpha_beta_miller_loop_result(&self) -> Result<GtElement<E>, GaragaError> {
+        let beta_g2 = checked_lines::<E>(&self.verifying_key.beta_g2, PointId::BetaG2)?;
+
+        Ok(GtElement::new(multi_miller_loop(&[(
+            self.verifying_key.alpha_g1,
+            &beta_g2,
+        )])))
+    }
+}
+
+/// The Miller loop lines of the fixed G2 points of a verifying key
+///
+/// Garaga's verifiers embed them as constants rather than computing them on chain.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound = "")
+)]
+pub struct PrecomputedLines<E: GaragaCurve> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "impl_serde::serialize_lines",
+            deserialize_with = "impl_serde::deserialize_lines"
+        )
+    )]
+    gamma_g2: Vec<G2Line<E>>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "impl_serde::serialize_lines",
+            deserialize_with = "impl_serde::deserialize_lines"
+        )
+    )]
+    delta_g2: Vec<G2Line<E>>,
+}
+
+impl<E: GaragaCurve> PrecomputedLines<E> {
+    /// The lines of `gamma_g2`, in the order its Miller loop uses them
+    pub fn gamma_g2(&self) -> &Vec<G2Line<E>> {
+        &self.gamma_g2
+    }
+
+    /// The lines of `delta_g2`, in the order its Miller loop uses them
+    pub fn delta_g2(&self) -> &Vec<G2Line<E>> {
+        &self.delta_g2
+    }
+
+    /// The lines of both points, in the order the multi Miller loop uses them
+    ///
+    /// At each step come the lines of `gamma_g2`, then the ones of `delta_g2`.
+    pub fn interleaved(&self) -> Vec<G2Line<E>> {
+        let mut gamma_g2 = self.gamma_g2.iter();
+        let mut delta_g2 = self.delta_g2.iter();
+
+        let mut lines = Vec::with_capacity(self.gamma_g2.len() + self.delta_g2.len());
+        for step_size in step_sizes::<E>() {
+            lines.extend(gamma_g2.by_ref().take(step_size));
+            lines.extend(delta_g2.by_ref().take(step_size));
+        }
+        for (gamma_line, delta_line) in gamma_g2.zip(delta_g2) {
+            lines.extend([gamma_line, delta_line]);
+        }
+
+        lines
+    }
+}
+
 #[cfg(feature = "serde")]
 mod impl_serde {
-    use crate::serde::VerifyingKey as SerdeVerifyingKey;
+    use crate::{
+        serde::{G2Line as SerdeG2Line, VerifyingKey as SerdeVerifyingKey},
+        G2Line, GaragaCurve,
+    };
 
     use ark_ec::pairing::Pairing;
     use ark_groth16::VerifyingKey;
-    use serde::{Serialize, Serializer};
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 
     pub(super) fn serialize_verifying_key<S: Serializer, E: Pairing>(
         verifying_key: &VerifyingKey<E>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let vk = SerdeVerifyingKey::from(verifying_key);
+        let vk = SerdeVerifyingKey::try_from(verifying_key).map_err(ser::Error::custom)?;
         vk.serialize(serializer)
     }
+
+    pub(super) fn deserialize_verifying_key<'de, D: Deserializer<'de>, E: Pairing>(
+        deserializer: D,
+    ) -> Result<VerifyingKey<E>, D::Error> {
+        let vk = SerdeVerifyingKey::deserialize(deserializer)?;
+        vk.to_ark::<E>().map_err(de::Error::custom)
+    }
+
+    pub(super) fn serialize_lines<S: Serializer, E: GaragaCurve>(
+        lines: &[G2Line<E>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let lines = lines
+            .iter()
+            .map(SerdeG2Line::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ser::Error::custom)?;
+        lines.serialize(serializer)
+    }
+
+    pub(super) fn deserialize_lines<'de, D: Deserializer<'de>, E: GaragaCurve>(
+        deserializer: D,
+    ) -> Result<Vec<G2Line<E>>, D::Error> {
+        let lines = Vec::<SerdeG2Line>::deserialize(deserializer)?;
+        lines
+            .iter()
+            .map(SerdeG2Line::to_ark::<E>)
+            .collect::<Result<_, _>>()
+            .map_err(de::Error::custom)
+    }
 }
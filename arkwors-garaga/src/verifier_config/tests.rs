@@ -1,5 +1,9 @@
 mod groth_16_verifier_configuration {
-    use crate::{ElipticCurveId, Groth16VerifierConfiguration};
+    use crate::{
+        test_utils::{outside_subgroup, random_verifying_key},
+        ElipticCurveId, GaragaError, Groth16VerifierConfiguration,
+    };
+    use assert_matches::assert_matches;
     use serde_test::{assert_ser_tokens, Token};
 
     use ark_groth16::VerifyingKey;
@@ -9,17 +13,41 @@ mod groth_16_verifier_configuration {
         let verifying_key = VerifyingKey::<ark_bn254::Bn254>::default();
 
         let config =
-            Groth16VerifierConfiguration::new(ElipticCurveId::Bn254, verifying_key.clone());
+            Groth16VerifierConfiguration::new(ElipticCurveId::Bn254, verifying_key.clone())
+                .unwrap();
         assert_eq!(config.eliptic_curve_id(), ElipticCurveId::Bn254);
         assert_eq!(config.verifying_key(), &verifying_key);
     }
 
+    #[test]
+    fn new_rejects_curve_mismatch() {
+        let verifying_key = VerifyingKey::<ark_bn254::Bn254>::default();
+
+        assert_matches!(
+            Groth16VerifierConfiguration::new(ElipticCurveId::Bls12_381, verifying_key),
+            Err(GaragaError::CurveMismatch {
+                expected: ElipticCurveId::Bn254,
+                found: ElipticCurveId::Bls12_381
+            })
+        );
+    }
+
+    #[test]
+    fn from_verifying_key() {
+        let verifying_key = VerifyingKey::<ark_bls12_381::Bls12_381>::default();
+
+        let config = Groth16VerifierConfiguration::from_verifying_key(verifying_key.clone());
+        assert_eq!(config.eliptic_curve_id(), ElipticCurveId::Bls12_381);
+        assert_eq!(config.verifying_key(), &verifying_key);
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serialization() {
         let verifying_key = VerifyingKey::<ark_bn254::Bn254>::default();
 
-        let config = Groth16VerifierConfiguration::new(ElipticCurveId::Bn254, verifying_key);
+        let config =
+            Groth16VerifierConfiguration::new(ElipticCurveId::Bn254, verifying_key).unwrap();
         assert_ser_tokens(
             &config,
             &[
@@ -108,4 +136,230 @@ mod groth_16_verifier_configuration {
             r#"{"eliptic_curve_id":"bn254","verifying_key":{"alpha_g1":{"x":"0x0","y":"0x0"},"beta_g2":{"x":["0x0","0x0"],"y":["0x0","0x0"]},"gamma_g2":{"x":["0x0","0x0"],"y":["0x0","0x0"]},"delta_g2":{"x":["0x0","0x0"],"y":["0x0","0x0"]},"ic":[]}}"#
         )
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization() {
+        let config = Groth16VerifierConfiguration::new(
+            ElipticCurveId::Bn254,
+            random_verifying_key::<ark_bn254::Bn254>(3),
+        )
+        .unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Groth16VerifierConfiguration<_>>(&json).unwrap(),
+            config
+        );
+
+        let config = Groth16VerifierConfiguration::new(
+            ElipticCurveId::Bls12_381,
+            random_verifying_key::<ark_bls12_381::Bls12_381>(3),
+        )
+        .unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Groth16VerifierConfiguration<_>>(&json).unwrap(),
+            config
+        );
+
+        let config = Groth16VerifierConfiguration::new(
+            ElipticCurveId::Bn254,
+            VerifyingKey::<ark_bn254::Bn254>::default(),
+        )
+        .unwrap();
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Groth16VerifierConfiguration<_>>(&json).unwrap(),
+            config
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_invalid_points() {
+        use crate::serde::G2Point;
+
+        let config = Groth16VerifierConfiguration::new(
+            ElipticCurveId::Bn254,
+            random_verifying_key::<ark_bn254::Bn254>(1),
+        )
+        .unwrap();
+        let mut json: serde_json::Value = serde_json::to_value(&config).unwrap();
+
+        // Coordinate bigger than the base field modulus
+        let mut out_of_range = json.clone();
+        out_of_range["verifying_key"]["alpha_g1"]["x"] = serde_json::Value::String(
+            "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47".to_string(),
+        );
+        let err =
+            serde_json::from_value::<Groth16VerifierConfiguration<ark_bn254::Bn254>>(out_of_range)
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid point `alpha_g1`: coordinate `0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47` is not a valid field element"));
+
+        // Point not on the curve
+        let mut not_on_curve = json.clone();
+        not_on_curve["verifying_key"]["ic"][0]["y"] = serde_json::Value::String("0x1".to_string());
+        let err =
+            serde_json::from_value::<Groth16VerifierConfiguration<ark_bn254::Bn254>>(not_on_curve)
+                .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid point `ic[0]`: point is not on the curve"));
+
+        // Point on the twist but outside of the prime order subgroup
+        let point = outside_subgroup::<ark_bn254::g2::Config>();
+        assert!(!point.is_in_correct_subgroup_assuming_on_curve());
+        json["verifying_key"]["delta_g2"] =
+            serde_json::to_value(G2Point::from_ark::<ark_bn254::Bn254>(&point).unwrap()).unwrap();
+        let err = serde_json::from_value::<Groth16VerifierConfiguration<ark_bn254::Bn254>>(json)
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid point `delta_g2`: point is not in the prime order subgroup"));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_curve_mismatch() {
+        let config = Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<
+            ark_bn254::Bn254,
+        >::default());
+        let json = serde_json::to_string(&config).unwrap();
+
+        let err =
+            serde_json::from_str::<Groth16VerifierConfiguration<ark_bls12_381::Bls12_381>>(&json)
+                .unwrap_err();
+        assert!(err.to_string().contains("eliptic curve mismatch"));
+    }
+
+    fn precomputed_lines_for<E: crate::GaragaCurve>() {
+        use crate::pairing::lines;
+
+        let config = Groth16VerifierConfiguration::from_verifying_key(random_verifying_key::<E>(2));
+
+        let precomputed_lines = config.precomputed_lines().unwrap();
+        let gamma_g2 = lines::<E>(&config.verifying_key().gamma_g2).unwrap();
+        let delta_g2 = lines::<E>(&config.verifying_key().delta_g2).unwrap();
+        assert_eq!(precomputed_lines.gamma_g2(), &gamma_g2);
+        assert_eq!(precomputed_lines.delta_g2(), &delta_g2);
+
+        let interleaved = precomputed_lines.interleaved();
+        assert_eq!(interleaved.len(), gamma_g2.len() + delta_g2.len());
+        assert_eq!(interleaved[0], gamma_g2[0]);
+        assert_eq!(
+            interleaved[interleaved.len() - 2..],
+            [gamma_g2[gamma_g2.len() - 1], delta_g2[delta_g2.len() - 1]]
+        );
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&precomputed_lines).unwrap();
+            assert_eq!(
+                serde_json::from_str::<crate::PrecomputedLines<E>>(&json).unwrap(),
+                precomputed_lines
+            );
+        }
+    }
+
+    #[test]
+    fn precomputed_lines() {
+        precomputed_lines_for::<ark_bn254::Bn254>();
+        precomputed_lines_for::<ark_bls12_381::Bls12_381>();
+    }
+
+    #[test]
+    fn precomputed_lines_rejects_point_outside_subgroup() {
+        let point = outside_subgroup::<ark_bn254::g2::Config>();
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<ark_bn254::Bn254> {
+                gamma_g2: point,
+                ..random_verifying_key(1)
+            });
+
+        assert_matches!(
+            config.precomputed_lines(),
+            Err(GaragaError::InvalidPoint {
+                point: crate::PointId::GammaG2,
+                reason: crate::InvalidPointReason::NotInSubgroup
+            })
+        );
+    }
+
+    #[test]
+    fn validate_points() {
+        use ark_ec::{short_weierstrass::Affine, AffineRepr};
+        use ark_ff::One;
+
+        let vk = random_verifying_key::<ark_bls12_381::Bls12_381>(3);
+        Groth16VerifierConfiguration::from_verifying_key(vk.clone())
+            .validate_points()
+            .unwrap();
+
+        let (x, y) = vk.alpha_g1.xy().unwrap();
+        let off_curve = Affine::new_unchecked(*x, *y + ark_bls12_381::Fq::one());
+        let config = Groth16VerifierConfiguration::from_verifying_key(VerifyingKey {
+            alpha_g1: off_curve,
+            ..vk.clone()
+        });
+        assert_matches!(
+            config.validate_points(),
+            Err(GaragaError::InvalidPoint {
+                point: crate::PointId::AlphaG1,
+                reason: crate::InvalidPointReason::NotOnCurve
+            })
+        );
+
+        let mut gamma_abc_g1 = vk.gamma_abc_g1.clone();
+        gamma_abc_g1[2] = outside_subgroup::<ark_bls12_381::g1::Config>();
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey { gamma_abc_g1, ..vk });
+        assert_matches!(
+            config.validate_points(),
+            Err(GaragaError::InvalidPoint {
+                point: crate::PointId::Ic(2),
+                reason: crate::InvalidPointReason::NotInSubgroup
+            })
+        );
+    }
+
+    #[test]
+    fn precomputed_lines_rejects_point_at_infinity() {
+        use ark_ec::AffineRepr;
+
+        let config =
+            Groth16VerifierConfiguration::from_verifying_key(VerifyingKey::<ark_bn254::Bn254> {
+                delta_g2: ark_bn254::G2Affine::zero(),
+                ..random_verifying_key(1)
+            });
+
+        assert_matches!(
+            config.precomputed_lines(),
+            Err(GaragaError::InvalidPoint {
+                point: crate::PointId::DeltaG2,
+                reason: crate::InvalidPointReason::AtInfinity
+            })
+        );
+    }
+
+    fn alpha_beta_miller_loop_result_for<E: crate::GaragaCurve>() {
+        use ark_ec::pairing::MillerLoopOutput;
+
+        let config = Groth16VerifierConfiguration::from_verifying_key(random_verifying_key::<E>(2));
+
+        let miller_loop_result = config.alpha_beta_miller_loop_result().unwrap();
+        assert_eq!(
+            E::final_exponentiation(MillerLoopOutput(*miller_loop_result.value()))
+                .unwrap()
+                .0,
+            ark_groth16::prepare_verifying_key(config.verifying_key()).alpha_g1_beta_g2
+        );
+    }
+
+    #[test]
+    fn alpha_beta_miller_loop_result() {
+        alpha_beta_miller_loop_result_for::<ark_bn254::Bn254>();
+        alpha_beta_miller_loop_result_for::<ark_bls12_381::Bls12_381>();
+    }
 }
@@ -1,40 +1,108 @@
 use ark_ec::pairing::Pairing;
-use ark_groth16::Proof;
+use ark_groth16::{prepare_verifying_key, Groth16, Proof};
 
-use crate::ElipticCurveId;
+use crate::{
+    check_eliptic_curve_id, check_not_at_infinity, check_point, check_public_input_count,
+    ElipticCurveId, GaragaCurve, GaragaError, Groth16VerifierConfiguration, PointId,
+};
 
 #[cfg(test)]
 mod tests;
 
+/// A proof and the public inputs it was generated for, as the payload of a verifier contract
+///
+/// A proof whose points are at infinity is never legitimate, so serializing or
+/// deserializing one fails rather than writing it with Garaga's `(0, 0)` encoding.
 #[derive(Clone, Debug, PartialEq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(deserialize = "E: GaragaCurve"))
+)]
 pub struct Groth16VerifierInputs<E: Pairing> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::serde::deserialize_eliptic_curve_id::<_, E>")
+    )]
     eliptic_curve_id: ElipticCurveId,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "impl_serde::serialize_proof")
+        serde(
+            serialize_with = "impl_serde::serialize_proof",
+            deserialize_with = "impl_serde::deserialize_proof"
+        )
     )]
     proof: Proof<E>,
     #[cfg_attr(
         feature = "serde",
-        serde(serialize_with = "impl_serde::serialize_public_inputs::<_, E>")
+        serde(
+            serialize_with = "impl_serde::serialize_public_inputs::<_, E>",
+            deserialize_with = "impl_serde::deserialize_public_inputs::<_, E>"
+        )
     )]
     public_inputs: Vec<E::ScalarField>,
 }
 
-impl<E: Pairing> Groth16VerifierInputs<E> {
+impl<E: GaragaCurve> Groth16VerifierInputs<E> {
+    /// Fails if `eliptic_curve_id` is not the curve of `E`
     pub fn new(
         eliptic_curve_id: ElipticCurveId,
         proof: Proof<E>,
         public_inputs: Vec<E::ScalarField>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, GaragaError> {
+        check_eliptic_curve_id::<E>(eliptic_curve_id)?;
+
+        Ok(Self {
             eliptic_curve_id,
             proof,
             public_inputs,
+        })
+    }
+
+    /// Same as `new`, with the eliptic curve id inferred from `E`
+    pub fn from_proof(proof: Proof<E>, public_inputs: Vec<E::ScalarField>) -> Self {
+        Self {
+            eliptic_curve_id: E::ELIPTIC_CURVE_ID,
+            proof,
+            public_inputs,
+        }
+    }
+
+    /// Make sure `a`, `b` and `c` are on the curve, in its prime order subgroup,
+    /// and not the point at infinity
+    ///
+    /// The error names the first point that is not.
+    pub fn validate_points(&self) -> Result<(), GaragaError> {
+        check_not_at_infinity(&self.proof.a, PointId::A)?;
+        check_point(&self.proof.a, PointId::A)?;
+        check_not_at_infinity(&self.proof.b, PointId::B)?;
+        check_point(&self.proof.b, PointId::B)?;
+        check_not_at_infinity(&self.proof.c, PointId::C)?;
+        check_point(&self.proof.c, PointId::C)
+    }
+
+    /// Run the Groth16 pairing check against the verifying key of `config`
+    ///
+    /// Fails if the points of the proof are invalid, if the number of public inputs doesn't
+    /// match the verifying key, or with `GaragaError::PairingCheckFailed` if the proof doesn't
+    /// verify, in which case Garaga's verifier would reject it too.
+    /// Errors of Arkworks' verifier, about a malformed key, come through as
+    /// `GaragaError::Synthesis`.
+    pub fn verify(&self, config: &Groth16VerifierConfiguration<E>) -> Result<(), GaragaError> {
+        self.validate_points()?;
+        let vk = config.verifying_key();
+        check_public_input_count(vk, self.public_inputs.len())?;
+
+        let pvk = prepare_verifying_key(vk);
+        if !Groth16::<E>::verify_proof(&pvk, &self.proof, &self.public_inputs)? {
+            return Err(GaragaError::PairingCheckFailed);
         }
+
+        Ok(())
     }
+}
 
+impl<E: Pairing> Groth16VerifierInputs<E> {
     pub fn eliptic_curve_id(&self) -> ElipticCurveId {
         self.eliptic_curve_id
     }
@@ -50,17 +118,19 @@ impl<E: Pairing> Groth16VerifierInputs<E> {
 
 #[cfg(feature = "serde")]
 mod impl_serde {
-    use crate::serde::Proof as SerdeProof;
+    use crate::{
+        serde::{field_from_hex, public_inputs_to_hex, Proof as SerdeProof},
+        GaragaError,
+    };
     use ark_ec::pairing::Pairing;
     use ark_groth16::Proof;
-    use num_bigint::BigUint;
-    use serde::{Serialize, Serializer};
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 
     pub(super) fn serialize_proof<S: Serializer, E: Pairing>(
         proof: &Proof<E>,
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let p = SerdeProof::from(proof);
+        let p = SerdeProof::try_from(proof).map_err(ser::Error::custom)?;
         p.serialize(serializer)
     }
 
@@ -68,14 +138,27 @@ mod impl_serde {
         public_inputs: &[E::ScalarField],
         serializer: S,
     ) -> Result<S::Ok, S::Error> {
-        let hex_strings = public_inputs
+        public_inputs_to_hex(public_inputs).serialize(serializer)
+    }
+
+    pub(super) fn deserialize_proof<'de, D: Deserializer<'de>, E: Pairing>(
+        deserializer: D,
+    ) -> Result<Proof<E>, D::Error> {
+        let p = SerdeProof::deserialize(deserializer)?;
+        p.to_ark::<E>().map_err(de::Error::custom)
+    }
+
+    pub(super) fn deserialize_public_inputs<'de, D: Deserializer<'de>, E: Pairing>(
+        deserializer: D,
+    ) -> Result<Vec<E::ScalarField>, D::Error> {
+        let hex_strings = Vec::<String>::deserialize(deserializer)?;
+
+        hex_strings
             .iter()
-            .map(|&v| {
-                let bi: BigUint = <E::ScalarField as Into<BigUint>>::into(v);
-                format!("{:#01x}", bi)
+            .map(|v| {
+                field_from_hex(v)
+                    .ok_or_else(|| de::Error::custom(GaragaError::InvalidFieldElement(v.clone())))
             })
-            .collect::<Vec<String>>();
-
-        hex_strings.serialize(serializer)
+            .collect()
     }
 }
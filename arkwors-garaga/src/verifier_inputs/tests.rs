@@ -4,10 +4,14 @@ mod groth_16_verifier_inputs {
         test_rng, UniformRand,
     };
 
-    use ark_ec::pairing::Pairing;
+    use ark_ec::{pairing::Pairing, AffineRepr};
     use ark_groth16::Proof;
 
-    use crate::Groth16VerifierInputs;
+    use crate::{
+        test_utils::{outside_subgroup, valid_proof},
+        GaragaError, Groth16VerifierConfiguration, Groth16VerifierInputs,
+    };
+    use assert_matches::assert_matches;
 
     #[test]
     fn new_and_getters() {
@@ -22,17 +26,95 @@ mod groth_16_verifier_inputs {
             crate::ElipticCurveId::Bn254,
             proof.clone(),
             public_inputs.clone(),
+        )
+        .unwrap();
+        assert_eq!(inputs.eliptic_curve_id(), crate::ElipticCurveId::Bn254);
+        assert_eq!(inputs.proof(), &proof);
+        assert_eq!(inputs.public_inputs(), &public_inputs);
+    }
+
+    #[test]
+    fn new_rejects_curve_mismatch() {
+        let proof = Proof::<ark_bn254::Bn254>::default();
+
+        assert_matches!(
+            Groth16VerifierInputs::new(crate::ElipticCurveId::Bls12_381, proof, vec![]),
+            Err(GaragaError::CurveMismatch {
+                expected: crate::ElipticCurveId::Bn254,
+                found: crate::ElipticCurveId::Bls12_381
+            })
         );
+    }
+
+    #[test]
+    fn from_proof() {
+        let proof = Proof::<ark_bls12_381::Bls12_381>::default();
+        let public_inputs = vec![<ark_bls12_381::Bls12_381 as Pairing>::ScalarField::from(
+            1u8,
+        )];
+
+        let inputs = Groth16VerifierInputs::from_proof(proof.clone(), public_inputs.clone());
+        assert_eq!(inputs.eliptic_curve_id(), crate::ElipticCurveId::Bls12_381);
         assert_eq!(inputs.proof(), &proof);
         assert_eq!(inputs.public_inputs(), &public_inputs);
     }
 
+    #[test]
+    fn verify() {
+        let (vk, proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(2);
+        let config = Groth16VerifierConfiguration::from_verifying_key(vk);
+
+        let inputs = Groth16VerifierInputs::from_proof(proof.clone(), public_inputs.clone());
+        inputs.verify(&config).unwrap();
+
+        let mut wrong_inputs = public_inputs.clone();
+        wrong_inputs[1] += <ark_bn254::Bn254 as Pairing>::ScalarField::from(1u8);
+        let inputs = Groth16VerifierInputs::from_proof(proof.clone(), wrong_inputs);
+        assert_matches!(inputs.verify(&config), Err(GaragaError::PairingCheckFailed));
+
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs[..1].to_vec());
+        assert_matches!(
+            inputs.verify(&config),
+            Err(GaragaError::PublicInputCountMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn validate_points() {
+        let (vk, mut proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(1);
+        let inputs = Groth16VerifierInputs::from_proof(proof.clone(), public_inputs.clone());
+        inputs.validate_points().unwrap();
+
+        proof.b = outside_subgroup::<ark_bn254::g2::Config>();
+        let inputs = Groth16VerifierInputs::from_proof(proof, public_inputs);
+        let invalid_b = |result| {
+            matches!(
+                result,
+                Err(GaragaError::InvalidPoint {
+                    point: crate::PointId::B,
+                    reason: crate::InvalidPointReason::NotInSubgroup
+                })
+            )
+        };
+        assert!(invalid_b(inputs.validate_points()));
+        assert!(invalid_b(
+            inputs.verify(&Groth16VerifierConfiguration::from_verifying_key(vk))
+        ));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serialization() {
         use serde_test::{assert_ser_tokens, Token};
 
-        let proof = Proof::<ark_bn254::Bn254>::default();
+        let proof = Proof::<ark_bn254::Bn254> {
+            a: ark_bn254::G1Affine::generator(),
+            b: ark_bn254::G2Affine::generator(),
+            c: ark_bn254::G1Affine::generator(),
+        };
 
         let a = <ark_bn254::Bn254 as Pairing>::ScalarField::from(1u8);
         let b = <ark_bn254::Bn254 as Pairing>::ScalarField::from(2u8);
@@ -42,7 +124,8 @@ mod groth_16_verifier_inputs {
             crate::ElipticCurveId::Bn254,
             proof.clone(),
             public_inputs.clone(),
-        );
+        )
+        .unwrap();
         assert_ser_tokens(
             &inputs,
             &[
@@ -66,9 +149,9 @@ mod groth_16_verifier_inputs {
                     len: 2,
                 },
                 Token::Str("x"),
-                Token::Str("0x0"),
+                Token::Str("0x1"),
                 Token::Str("y"),
-                Token::Str("0x0"),
+                Token::Str("0x2"),
                 Token::StructEnd,
                 Token::Str("b"),
                 Token::Struct {
@@ -77,13 +160,13 @@ mod groth_16_verifier_inputs {
                 },
                 Token::Str("x"),
                 Token::Tuple { len: 2 },
-                Token::Str("0x0"),
-                Token::Str("0x0"),
+                Token::Str("0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+                Token::Str("0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
                 Token::TupleEnd,
                 Token::Str("y"),
                 Token::Tuple { len: 2 },
-                Token::Str("0x0"),
-                Token::Str("0x0"),
+                Token::Str("0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+                Token::Str("0x90689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
                 Token::TupleEnd,
                 Token::StructEnd,
                 Token::Str("c"),
@@ -92,9 +175,9 @@ mod groth_16_verifier_inputs {
                     len: 2,
                 },
                 Token::Str("x"),
-                Token::Str("0x0"),
+                Token::Str("0x1"),
                 Token::Str("y"),
-                Token::Str("0x0"),
+                Token::Str("0x2"),
                 Token::StructEnd,
                 Token::StructEnd,
                 Token::Str("public_inputs"),
@@ -108,7 +191,89 @@ mod groth_16_verifier_inputs {
 
         assert_eq!(
             &serde_json::to_string(&inputs).unwrap(),
-            r#"{"eliptic_curve_id":"bn254","proof":{"a":{"x":"0x0","y":"0x0"},"b":{"x":["0x0","0x0"],"y":["0x0","0x0"]},"c":{"x":"0x0","y":"0x0"}},"public_inputs":["0x1","0x2"]}"#
+            r#"{"eliptic_curve_id":"bn254","proof":{"a":{"x":"0x1","y":"0x2"},"b":{"x":["0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed","0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"],"y":["0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa","0x90689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"]},"c":{"x":"0x1","y":"0x2"}},"public_inputs":["0x1","0x2"]}"#
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization() {
+        let mut rng = test_rng();
+        let proof = Proof::<ark_bn254::Bn254> {
+            a: UniformRand::rand(&mut rng),
+            b: UniformRand::rand(&mut rng),
+            c: UniformRand::rand(&mut rng),
+        };
+        let public_inputs = vec![
+            <ark_bn254::Bn254 as Pairing>::ScalarField::rand(&mut rng),
+            <ark_bn254::Bn254 as Pairing>::ScalarField::from(2u8),
+        ];
+
+        let inputs =
+            Groth16VerifierInputs::new(crate::ElipticCurveId::Bn254, proof, public_inputs).unwrap();
+        let json = serde_json::to_string(&inputs).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Groth16VerifierInputs<_>>(&json).unwrap(),
+            inputs
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn deserialization_rejects_out_of_range_public_inputs() {
+        // The scalar field modulus itself
+        let json = r#"{"eliptic_curve_id":"bn254","proof":{"a":{"x":"0x1","y":"0x2"},"b":{"x":["0x1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed","0x198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"],"y":["0x12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa","0x90689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"]},"c":{"x":"0x1","y":"0x2"}},"public_inputs":["0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001"]}"#;
+
+        let err =
+            serde_json::from_str::<Groth16VerifierInputs<ark_bn254::Bn254>>(json).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "`0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001` is not a valid field element"
+        ));
+    }
+
+    #[test]
+    fn validate_points_rejects_points_at_infinity() {
+        let (_, proof, public_inputs) = valid_proof::<ark_bls12_381::Bls12_381>(1);
+        let inputs = Groth16VerifierInputs::from_proof(
+            Proof {
+                c: ark_bls12_381::G1Affine::zero(),
+                ..proof
+            },
+            public_inputs,
+        );
+
+        assert_matches!(
+            inputs.validate_points(),
+            Err(GaragaError::InvalidPoint {
+                point: crate::PointId::C,
+                reason: crate::InvalidPointReason::AtInfinity
+            })
         );
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialization_rejects_points_at_infinity() {
+        let (_, proof, public_inputs) = valid_proof::<ark_bn254::Bn254>(1);
+        let inputs = Groth16VerifierInputs::from_proof(
+            Proof {
+                b: ark_bn254::G2Affine::zero(),
+                ..proof
+            },
+            public_inputs,
+        );
+
+        let err = serde_json::to_string(&inputs).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid point `b`: point is the point at infinity"
+        );
+
+        let json = r#"{"eliptic_curve_id":"bn254","proof":{"a":{"x":"0x0","y":"0x0"},"b":{"x":["0x0","0x0"],"y":["0x0","0x0"]},"c":{"x":"0x0","y":"0x0"}},"public_inputs":[]}"#;
+        let err =
+            serde_json::from_str::<Groth16VerifierInputs<ark_bn254::Bn254>>(json).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("invalid point `a`: point is the point at infinity"));
+    }
 }